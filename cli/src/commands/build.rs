@@ -1,15 +1,66 @@
 use anyhow::{Context, Result};
-use near_multisig_lib::{build_manifest::BuildManifest, checksum::SHA256SUMS};
+use near_multisig_lib::{
+    build_manifest::{
+        git_commit, load_signing_key, BuildManifest, DEFAULT_SOURCE_PATHS, GIT_COMMIT_ENV,
+        SIGNATURE_FILENAME,
+    },
+    checksum::SHA256SUMS,
+};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-pub fn run(release_dir: &str) -> Result<()> {
-    println!("Building WASM...");
+/// Growth over the previous build's wasm that earns a warning
+const SIGNIFICANT_GROWTH_PERCENT: f64 = 10.0;
 
-    // Run cargo near build (non-reproducible for now, fast local builds)
+pub fn run(
+    release_dir: &str,
+    source_hashes: bool,
+    source_paths: Vec<String>,
+    clean: bool,
+    sign: Option<&str>,
+    max_wasm_size: Option<u64>,
+    json: bool,
+) -> Result<()> {
+    // Load the key up front so a bad key file fails before a slow build
+    let signing_key = sign
+        .map(|path| load_signing_key(Path::new(path)))
+        .transpose()?;
+
+    // Checksums cover the whole release dir, so leftovers would end up in SHA256SUMS
+    let release_path = Path::new(release_dir);
+    // Read before --clean removes it, to report how much the wasm grew
+    let previous_size = BuildManifest::from_file(&release_path.join("build-manifest.json"))
+        .ok()
+        .map(|previous| previous.output.wasm_size);
+    let stale = stale_artifacts(release_path)?;
+    if !stale.is_empty() {
+        if !clean {
+            let names: Vec<_> = stale.iter().map(|p| p.display().to_string()).collect();
+            anyhow::bail!(
+                "{} already contains build artifacts ({}); rerun with --clean to remove them",
+                release_dir,
+                names.join(", ")
+            );
+        }
+        for path in &stale {
+            fs::remove_file(path)
+                .with_context(|| format!("Failed to remove {}", path.display()))?;
+            if !json {
+                println!("✓ Removed stale: {}", path.display());
+            }
+        }
+    }
+
+    if !json {
+        println!("Building WASM...");
+    }
+
+    // Run cargo near build (non-reproducible for now, fast local builds); the contract embeds
+    // the commit so `get_binary_version` matches the manifest
     let status = Command::new("cargo")
         .args(["near", "build", "non-reproducible-wasm"])
+        .env(GIT_COMMIT_ENV, git_commit())
         .status()
         .context("Failed to run cargo near build")?;
 
@@ -31,10 +82,11 @@ pub fn run(release_dir: &str) -> Result<()> {
         })
         .context("No WASM file found in target/near/")?;
 
-    println!("✓ Built: {}", wasm_path.display());
+    if !json {
+        println!("✓ Built: {}", wasm_path.display());
+    }
 
     // Create release directory
-    let release_path = Path::new(release_dir);
     fs::create_dir_all(release_path)?;
 
     // Copy WASM to release/
@@ -45,14 +97,108 @@ pub fn run(release_dir: &str) -> Result<()> {
     // Generate SHA256SUMS
     let checksums = SHA256SUMS::from_directory(release_path)?;
     checksums.write_to_file(&release_path.join("SHA256SUMS"))?;
-    println!("✓ Generated: {}/SHA256SUMS", release_dir);
 
     // Generate build manifest
-    let manifest = BuildManifest::generate(&release_wasm)?;
+    let mut manifest = BuildManifest::generate(&release_wasm)?;
+    if source_hashes {
+        let paths = if source_paths.is_empty() {
+            DEFAULT_SOURCE_PATHS.iter().map(|p| p.to_string()).collect()
+        } else {
+            source_paths
+        };
+        manifest = manifest.with_source_files(Path::new("."), &paths)?;
+    }
     manifest.write_to_file(&release_path.join("build-manifest.json"))?;
-    println!("✓ Generated: {}/build-manifest.json", release_dir);
 
+    let signed = signing_key.map(|key| manifest.sign(&key));
+    if let Some(signed) = &signed {
+        signed.write_to_file(&release_path.join(SIGNATURE_FILENAME))?;
+    }
+
+    let wasm_size = manifest.output.wasm_size;
+    let size_delta = previous_size.map(|previous| wasm_size as i64 - previous as i64);
+    let within_limit = max_wasm_size.is_none_or(|max| wasm_size <= max);
+    let too_large = || {
+        anyhow::anyhow!(
+            "WASM is {} bytes, over the --max-wasm-size limit of {} bytes",
+            wasm_size,
+            max_wasm_size.unwrap_or_default()
+        )
+    };
+
+    if json {
+        let output = serde_json::json!({
+            "wasm": release_wasm,
+            "wasm_hash": manifest.output.wasm_hash,
+            "wasm_size": wasm_size,
+            "wasm_size_delta": size_delta,
+            "binary_version": manifest.output.binary_version,
+            "max_wasm_size": max_wasm_size,
+            "checksums": release_path.join("SHA256SUMS"),
+            "manifest": release_path.join("build-manifest.json"),
+            "signature": signed.as_ref().map(|_| release_path.join(SIGNATURE_FILENAME)),
+            "signer": signed.as_ref().map(|s| &s.public_key),
+            "success": within_limit,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        if !within_limit {
+            return Err(too_large());
+        }
+        return Ok(());
+    }
+
+    match (previous_size, size_delta) {
+        (Some(previous), Some(delta)) => {
+            let growth = delta as f64 * 100.0 / previous.max(1) as f64;
+            let marker = if growth >= SIGNIFICANT_GROWTH_PERCENT {
+                "⚠"
+            } else {
+                "✓"
+            };
+            println!(
+                "{} Size: {} bytes ({:+} bytes, {:+.1}% vs previous build)",
+                marker, wasm_size, delta, growth
+            );
+        }
+        _ => println!("✓ Size: {} bytes", wasm_size),
+    }
+
+    if let Some(version) = &manifest.output.binary_version {
+        println!("✓ Binary version: {}", version);
+    }
+    println!("✓ Generated: {}/SHA256SUMS", release_dir);
+    println!("✓ Generated: {}/build-manifest.json", release_dir);
+    if let Some(signed) = &signed {
+        println!(
+            "✓ Signed: {}/{} ({})",
+            release_dir, SIGNATURE_FILENAME, signed.public_key
+        );
+    }
     println!("✓ Artifacts ready in: {}/", release_dir);
 
+    if !within_limit {
+        return Err(too_large());
+    }
     Ok(())
 }
+
+/// Artifacts a previous build left in `release_path`: `*.wasm`, SHA256SUMS, the manifest and
+/// its signature
+fn stale_artifacts(release_path: &Path) -> Result<Vec<PathBuf>> {
+    if !release_path.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut stale = Vec::new();
+    for entry in fs::read_dir(release_path)? {
+        let path = entry?.path();
+        let is_wasm = path.extension().is_some_and(|ext| ext == "wasm");
+        let is_generated = path.file_name().is_some_and(|name| {
+            name == "SHA256SUMS" || name == "build-manifest.json" || name == SIGNATURE_FILENAME
+        });
+        if path.is_file() && (is_wasm || is_generated) {
+            stale.push(path);
+        }
+    }
+    stale.sort();
+    Ok(stale)
+}
@@ -11,7 +11,7 @@ edition = "2021"
 crate-type = ["cdylib"]
 
 [dependencies]
-near-sdk = "5.24.0"
+near-sdk = "{{sdk_version}}"
 
 [profile.release]
 codegen-units = 1
@@ -21,11 +21,24 @@ panic = "abort"
 overflow-checks = true
 
 [package.metadata.near.reproducible_build]
-image = "sourcescan/cargo-near:0.18.0-rust-1.86.0"
-image_digest = "sha256:2d0d458d2357277df669eac6fa23a1ac922e5ed16646e1d3315336e4dff18043"
+image = "{{build_image}}"
+image_digest = "{{build_image_digest}}"
 container_build_command = ["cargo", "near", "build", "reproducible-wasm"]
 "#;
 
+/// near-sdk version generated projects use unless `--sdk-version` is given
+pub const DEFAULT_SDK_VERSION: &str = "5.24.0";
+
+/// Known-good (near-sdk version, reproducible build image, image digest) triples
+///
+/// Only versions listed here can be selected with `--sdk-version`, so every generated
+/// project builds reproducibly with a pinned image.
+const SDK_BUILD_IMAGES: &[(&str, &str, &str)] = &[(
+    "5.24.0",
+    "sourcescan/cargo-near:0.18.0-rust-1.86.0",
+    "sha256:2d0d458d2357277df669eac6fa23a1ac922e5ed16646e1d3315336e4dff18043",
+)];
+
 const BASIC_TEMPLATE_LIB: &str = r#"use near_sdk::store::{UnorderedSet, Vector};
 use near_sdk::{near, require, AccountId, PanicOnDefault, env, Promise, NearToken, Gas, PromiseResult};
 
@@ -297,10 +310,16 @@ impl MultisigContract {
         );
         self.next_tx_id = self.next_tx_id.saturating_add(1);
 
-        // L-2 fix: Calculate expiration timestamp if hours provided
+        // L-2 fix: Calculate expiration timestamp if hours provided, rejecting overflow
+        // instead of clamping to a transaction that effectively never expires
         let expiration = expiration_hours.map(|hours| {
             let nanos_per_hour = 3_600_000_000_000u64; // 1 hour = 3.6 trillion nanoseconds
-            env::block_timestamp().saturating_add(hours.saturating_mul(nanos_per_hour))
+            hours
+                .checked_mul(nanos_per_hour)
+                .and_then(|duration| env::block_timestamp().checked_add(duration))
+                .unwrap_or_else(|| {
+                    env::panic_str("Expiration calculation overflow - expiration_hours too large")
+                })
         });
 
         let tx = Transaction {
@@ -749,6 +768,81 @@ impl MultisigContract {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, VMContext};
+
+    fn get_context(predecessor: AccountId) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor)
+            .attached_deposit(NearToken::from_yoctonear(TRANSACTION_STORAGE_DEPOSIT))
+            .build()
+    }
+
+    fn setup() -> MultisigContract {
+        testing_env!(get_context(accounts(0)));
+        MultisigContract::new(vec![accounts(0), accounts(1), accounts(2)], 2)
+    }
+
+    fn submit(contract: &mut MultisigContract, expiration_hours: Option<u64>) -> u64 {
+        let actions = vec![Action::Transfer { amount: 1000 }];
+        contract.submit_transaction(accounts(3), actions, expiration_hours)
+    }
+
+    #[test]
+    fn test_confirm_and_execute() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract, None);
+        assert_eq!(contract.reserved_balance, 1000);
+
+        testing_env!(get_context(accounts(1)));
+        contract.confirm_transaction(tx_id);
+        assert!(contract.has_confirmed(tx_id, accounts(1)));
+
+        contract.execute_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().executed);
+        assert_eq!(contract.pending_callbacks, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough confirmations")]
+    fn test_execute_requires_threshold() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract, None);
+        contract.execute_transaction(tx_id);
+    }
+
+    #[test]
+    fn test_expiration_from_hours() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract, Some(2));
+        assert_eq!(
+            contract.get_transaction(tx_id).unwrap().expiration,
+            Some(env::block_timestamp() + 2 * 3_600_000_000_000)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expiration_hours too large")]
+    fn test_expiration_overflow_rejected() {
+        // Saturating would store a proposal that never expires
+        let mut contract = setup();
+        submit(&mut contract, Some(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only submitter can cancel")]
+    fn test_only_submitter_can_cancel() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract, None);
+
+        testing_env!(get_context(accounts(1)));
+        contract.cancel_transaction(tx_id);
+    }
+}
 "#;
 
 const TIMELOCK_TEMPLATE_LIB: &str = r#"use near_sdk::store::{UnorderedSet, Vector};
@@ -2244,148 +2338,1169 @@ impl WeightedMultisig {
 }
 "#;
 
-const GITHUB_ACTIONS_WORKFLOW: &str = r#"name: Release Contract
+const DAO_TEMPLATE_LIB: &str = r#"use near_sdk::store::{UnorderedSet, Vector};
+use near_sdk::{near, require, AccountId, PanicOnDefault, env, Promise, NearToken, Gas, PromiseResult};
 
-on:
-  push:
-    tags:
-      - 'v[0-9]+*'
+/// Security: Maximum number of actions per transaction to prevent gas exhaustion
+const MAX_ACTIONS: usize = 10;
 
-permissions:
-  contents: write
+/// Security: Maximum args size (32KB) to prevent storage attacks
+const MAX_ARGS_LEN: usize = 32768;
 
-jobs:
-  release:
-    runs-on: ubuntu-latest
-    steps:
-      - uses: actions/checkout@v4
+/// Security: Maximum method name length
+const MAX_METHOD_NAME_LEN: usize = 256;
 
-      - name: Install Rust
-        uses: actions-rs/toolchain@v1
-        with:
-          toolchain: 1.86.0
-          target: wasm32-unknown-unknown
-          override: true
+/// Security: Maximum gas per action (100 TGas)
+const MAX_GAS_PER_ACTION: u64 = 100_000_000_000_000;
 
-      - name: Install cargo-near
-        run: |
-          curl --proto '=https' --tlsv1.2 -LsSf https://github.com/near/cargo-near/releases/download/cargo-near-v0.18.0/cargo-near-installer.sh | sh
+/// Security: Maximum total gas across all actions (250 TGas)
+const MAX_TOTAL_GAS: u64 = 250_000_000_000_000;
 
-      - name: Build contract
-        run: cargo near build non-reproducible-wasm
+/// Security: Maximum number of owners to prevent gas exhaustion
+const MAX_OWNERS: usize = 50;
 
-      - name: Generate verification artifacts
-        run: |
-          mkdir -p release
-          cp target/near/*.wasm release/
-          cd release
-          sha256sum -b *.wasm > SHA256SUMS
+/// Security: Maximum transactions to process per cleanup call (prevents DoS)
+const MAX_CLEANUP_BATCH: u32 = 100;
 
-      - name: Create GitHub Release
-        uses: softprops/action-gh-release@v1
-        with:
-          files: |
-            release/*.wasm
-            release/SHA256SUMS
-          generate_release_notes: true
-          body: |
-            ## Verification
+/// Storage cost per transaction (0.01 NEAR) - refundable on execution/cancellation
+const TRANSACTION_STORAGE_DEPOSIT: u128 = 10_000_000_000_000_000_000_000; // 0.01 NEAR
 
-            Download and verify the contract:
+/// Default callback gas (20 TGas) - can be configured per contract
+const DEFAULT_CALLBACK_GAS: u64 = 20_000_000_000_000;
 
-            ```bash
-            wget https://github.com/${{ github.repository }}/releases/download/${{ github.ref_name }}/SHA256SUMS
-            wget https://github.com/${{ github.repository }}/releases/download/${{ github.ref_name }}/*.wasm
-            sha256sum -c SHA256SUMS
-            ```
+/// Maximum signal title length
+const MAX_TITLE_LEN: usize = 200;
 
-            **Build Info**:
-            - Rust: 1.86.0
-            - cargo-near: 0.18.0
-            - near-sdk: 5.24.0
-            - Commit: ${{ github.sha }}
-"#;
+/// Maximum signal description length (4KB)
+const MAX_DESCRIPTION_LEN: usize = 4096;
 
-pub fn run(project_name: &str, template: &str) -> Result<()> {
-    // L-4 fix: Validate project name to prevent path traversal and filesystem issues
-    if project_name.is_empty() {
-        anyhow::bail!("Project name cannot be empty");
-    }
-    if project_name.contains('/') || project_name.contains('\\') {
-        anyhow::bail!("Project name cannot contain path separators");
-    }
-    if project_name.starts_with('.') || project_name.starts_with('-') {
-        anyhow::bail!("Project name cannot start with '.' or '-'");
-    }
-    if !project_name
-        .chars()
-        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
-    {
-        anyhow::bail!(
-            "Project name can only contain alphanumeric characters, hyphens, and underscores"
-        );
-    }
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct MultisigContract {
+    pub owners: UnorderedSet<AccountId>,
+    pub num_confirmations: u32,
+    pub transactions: Vector<Transaction>,
+    pub pending_callbacks: u32, // Track pending executions to prevent cleanup corruption
+    pub callback_gas: u64, // Gas allocated for execution callbacks (configurable)
+    pub storage_deposit: u128, // L-5 fix: Storage deposit per transaction (configurable)
+    pub next_tx_id: u64, // M-3 fix: Monotonic transaction ID counter (never decreases)
+    pub reserved_balance: u128, // M-2 fix: Total deposits reserved by pending transactions
+}
 
-    let project_path = Path::new(project_name);
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct Transaction {
+    pub id: u64,
+    pub kind: ProposalKind,
+    pub confirmations: Vec<AccountId>,
+    pub executed: bool,
+    pub cancelled: bool,
+    pub storage_depositor: AccountId, // Who paid storage deposit (gets refund)
+    pub expiration: Option<u64>, // L-2 fix: Optional expiration timestamp (nanoseconds)
+}
 
-    if project_path.exists() {
-        anyhow::bail!("Directory '{}' already exists", project_name);
-    }
+/// What a proposal does once it reaches the confirmation threshold
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub enum ProposalKind {
+    /// Runs `actions` on `receiver_id` via execute_transaction
+    Executable { receiver_id: AccountId, actions: Vec<Action> },
+    /// Text-only signaling vote: passes at the threshold, recorded on chain, moves no funds
+    Signal { title: String, description: String },
+}
 
-    // Select template
-    let (lib_template, template_description) = match template {
-        "basic" => (
-            BASIC_TEMPLATE_LIB,
-            "Basic M-of-N multisig with security hardening",
-        ),
-        "timelock" => (
-            TIMELOCK_TEMPLATE_LIB,
-            "Timelock multisig with delayed execution and security hardening",
-        ),
-        "weighted" => (
-            WEIGHTED_TEMPLATE_LIB,
-            "Weighted voting multisig with security hardening",
-        ),
-        _ => anyhow::bail!(
-            "Unknown template: {}. Available: basic, timelock, weighted",
-            template
-        ),
-    };
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub enum Action {
+    Transfer { amount: u128 },
+    FunctionCall {
+        method_name: String,
+        args: Vec<u8>,
+        gas: u64,
+        deposit: u128,
+    },
+}
 
-    println!(
-        "Creating multisig project: {} ({})",
-        project_name, template_description
-    );
+// Events for off-chain indexing
+#[near(event_json(standard = "multisig"))]
+pub enum MultisigEvent {
+    #[event_version("1.0.0")]
+    TransactionSubmitted { tx_id: u64, submitter: AccountId, receiver_id: AccountId },
 
-    // Create directory structure
-    fs::create_dir_all(project_path.join("src"))?;
-    fs::create_dir_all(project_path.join(".github/workflows"))?;
+    #[event_version("1.0.0")]
+    TransactionConfirmed { tx_id: u64, confirmer: AccountId, confirmations: u32 },
 
-    // Write Cargo.toml
-    let cargo_toml = BASIC_TEMPLATE_CARGO.replace("{{project_name}}", project_name);
-    fs::write(project_path.join("Cargo.toml"), cargo_toml)?;
+    #[event_version("1.0.0")]
+    TransactionExecuted { tx_id: u64, success: bool },
 
-    // Write src/lib.rs
-    fs::write(project_path.join("src/lib.rs"), lib_template)?;
+    #[event_version("1.0.0")]
+    TransactionCancelled { tx_id: u64, canceller: AccountId },
 
-    // Write GitHub Actions workflow
-    fs::write(
-        project_path.join(".github/workflows/release.yml"),
-        GITHUB_ACTIONS_WORKFLOW,
-    )?;
+    #[event_version("1.0.0")]
+    ConfirmationRevoked { tx_id: u64, revoker: AccountId, confirmations: u32 },
 
-    println!("✓ Created {}/", project_name);
-    println!("✓ Created {}/Cargo.toml", project_name);
-    println!("✓ Created {}/src/lib.rs", project_name);
-    println!("✓ Created {}/.github/workflows/release.yml", project_name);
-    println!("\nNext steps:");
-    println!("  cd {}", project_name);
-    println!("  git init && git add . && git commit -m 'Initial commit'");
-    println!("  git tag v0.1.0 && git push --tags  # Triggers auto-release");
-    println!("  OR: near-multisig build  # For local development");
-    println!(
-        "\n✓ All security fixes applied (callback handling, overflow protection, input validation)"
-    );
+    #[event_version("1.0.0")]
+    CallbackGasChanged { old_gas: u64, new_gas: u64, changer: AccountId },
 
-    Ok(())
+    #[event_version("1.0.0")]
+    StorageDepositChanged { old_deposit: u128, new_deposit: u128, changer: AccountId },
+
+    #[event_version("1.0.0")]
+    TransactionReady { tx_id: u64, confirmations: u32 },
+
+    #[event_version("1.0.0")]
+    TransactionsCleanedUp { count: u64, from_index: u64, to_index: u64, cleaner: AccountId },
+
+    #[event_version("1.0.0")]
+    SignalSubmitted { tx_id: u64, submitter: AccountId, title: String },
+
+    #[event_version("1.0.0")]
+    ProposalPassed { tx_id: u64, title: String, confirmations: u32 },
+}
+
+// Security: Safe transaction access methods to prevent u32 overflow attacks
+impl MultisigContract {
+    /// Safe transaction lookup by ID (M-3 fix: search by id field, not index)
+    fn get_tx(&self, tx_id: u64) -> Option<&Transaction> {
+        for i in 0..self.transactions.len() {
+            if let Some(tx) = self.transactions.get(i) {
+                if tx.id == tx_id {
+                    return Some(tx);
+                }
+            }
+        }
+        None
+    }
+
+    /// Find transaction index by ID
+    fn get_tx_index(&self, tx_id: u64) -> Option<u32> {
+        for i in 0..self.transactions.len() {
+            if let Some(tx) = self.transactions.get(i) {
+                if tx.id == tx_id {
+                    return Some(i);
+                }
+            }
+        }
+        None
+    }
+
+    /// Safe transaction index lookup (panics if not found)
+    fn get_tx_index_or_panic(&self, tx_id: u64) -> u32 {
+        self.get_tx_index(tx_id).expect("Transaction not found")
+    }
+
+    /// Get old bounds-checked lookup (kept for compatibility)
+    fn get_tx_legacy(&self, tx_id: u64) -> Option<&Transaction> {
+        if tx_id > u32::MAX as u64 {
+            return None;
+        }
+        self.transactions.get(tx_id as u32)
+    }
+
+    /// Safe transaction lookup (panics if not found)
+    fn get_tx_or_panic(&self, tx_id: u64) -> &Transaction {
+        self.get_tx(tx_id).expect("Transaction not found")
+    }
+
+    /// Reserve the next transaction ID (M-3 fix: monotonic counter, never reused)
+    fn next_id(&mut self) -> u64 {
+        let tx_id = self.next_tx_id;
+        // MEDIUM-1 fix: Check for counter overflow before incrementing
+        require!(
+            self.next_tx_id < u64::MAX,
+            "Transaction ID counter limit reached"
+        );
+        self.next_tx_id = self.next_tx_id.saturating_add(1);
+        tx_id
+    }
+
+    /// L-2 fix: Convert expiration hours to a timestamp, rejecting overflow instead of
+    /// clamping to a proposal that effectively never expires
+    fn expiration_from_hours(expiration_hours: Option<u64>) -> Option<u64> {
+        expiration_hours.map(|hours| {
+            let nanos_per_hour = 3_600_000_000_000u64; // 1 hour = 3.6 trillion nanoseconds
+            hours
+                .checked_mul(nanos_per_hour)
+                .and_then(|duration| env::block_timestamp().checked_add(duration))
+                .unwrap_or_else(|| {
+                    env::panic_str("Expiration calculation overflow - expiration_hours too large")
+                })
+        })
+    }
+
+    /// Mark a signal as passed and refund its storage deposit; nothing is executed
+    fn pass_signal(&self, tx: &mut Transaction, confirmations: u32) {
+        let ProposalKind::Signal { title, .. } = &tx.kind else {
+            return;
+        };
+        tx.executed = true;
+
+        MultisigEvent::ProposalPassed {
+            tx_id: tx.id,
+            title: title.clone(),
+            confirmations,
+        }.emit();
+
+        Promise::new(tx.storage_depositor.clone())
+            .transfer(NearToken::from_yoctonear(self.storage_deposit))
+            .detach();
+    }
+
+    /// Validate action vector to prevent attacks
+    fn validate_actions(actions: &Vec<Action>) -> u128 {
+        require!(!actions.is_empty(), "Actions cannot be empty");
+        require!(actions.len() <= MAX_ACTIONS, "Too many actions (max 10)");
+
+        let mut total_gas = 0u64;
+        let mut total_deposit = 0u128;
+
+        for action in actions {
+            match action {
+                Action::Transfer { amount } => {
+                    require!(*amount > 0, "Transfer amount must be positive");
+                    total_deposit = total_deposit.saturating_add(*amount);
+                }
+                Action::FunctionCall { method_name, args, gas, deposit } => {
+                    require!(args.len() <= MAX_ARGS_LEN, "Args too large (max 32KB)");
+                    require!(method_name.len() <= MAX_METHOD_NAME_LEN, "Method name too long");
+                    require!(!method_name.is_empty(), "Method name cannot be empty");
+
+                    // Security: Validate gas parameters (BUG-7)
+                    require!(*gas > 0, "Gas must be positive");
+                    require!(*gas <= MAX_GAS_PER_ACTION, "Gas per action exceeds limit (max 100 TGas)");
+                    total_gas = total_gas.saturating_add(*gas);
+
+                    // Accumulate deposits
+                    total_deposit = total_deposit.saturating_add(*deposit);
+                }
+            }
+        }
+
+        require!(total_gas <= MAX_TOTAL_GAS, "Total gas exceeds limit (max 250 TGas)");
+        total_deposit
+    }
+
+    /// M-2 fix: Calculate total deposits in a transaction
+    fn calculate_transaction_deposit(tx: &Transaction) -> u128 {
+        // Signals never reserve or move funds
+        let ProposalKind::Executable { actions, .. } = &tx.kind else {
+            return 0;
+        };
+        let mut total_deposit = 0u128;
+        for action in actions {
+            match action {
+                Action::Transfer { amount } => {
+                    total_deposit = total_deposit.saturating_add(*amount);
+                }
+                Action::FunctionCall { deposit, .. } => {
+                    total_deposit = total_deposit.saturating_add(*deposit);
+                }
+            }
+        }
+        total_deposit
+    }
+}
+
+#[near]
+impl MultisigContract {
+    #[init]
+    pub fn new(owners: Vec<AccountId>, num_confirmations: u32) -> Self {
+        require!(!owners.is_empty(), "Need at least one owner");
+        // Security: Enforce max owners limit (BUG-9)
+        require!(owners.len() <= MAX_OWNERS, "Too many owners (max 50)");
+        require!(
+            num_confirmations > 0 && num_confirmations <= owners.len() as u32,
+            "Invalid confirmation threshold"
+        );
+
+        // Security: Check for duplicate owners
+        let mut owner_set = UnorderedSet::new(b"o");
+        for owner in &owners {
+            require!(owner_set.insert(owner.clone()), "Duplicate owner");
+        }
+
+        Self {
+            owners: owner_set,
+            num_confirmations,
+            transactions: Vector::new(b"t"),
+            pending_callbacks: 0,
+            callback_gas: DEFAULT_CALLBACK_GAS,
+            storage_deposit: TRANSACTION_STORAGE_DEPOSIT, // L-5 fix
+            next_tx_id: 0, // M-3 fix
+            reserved_balance: 0, // M-2 fix
+        }
+    }
+
+    /// Submit a new transaction for approval
+    /// Requires 0.01 NEAR storage deposit (refunded on execution/cancellation)
+    #[payable]
+    pub fn submit_transaction(&mut self, receiver_id: AccountId, actions: Vec<Action>, expiration_hours: Option<u64>) -> u64 {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        // Security: Require storage deposit to prevent spam (M-1)
+        let attached = env::attached_deposit().as_yoctonear();
+        require!(
+            attached >= self.storage_deposit,
+            format!("Must attach at least {} yoctoNEAR for storage", self.storage_deposit)
+        );
+
+        // Security: Prevent sending to self
+        require!(
+            receiver_id != env::current_account_id(),
+            "Cannot send to multisig contract itself"
+        );
+
+        // Security: Validate actions and get total deposit
+        let total_deposit = Self::validate_actions(&actions);
+
+        // M-2 fix: Check available balance after accounting for reserved amounts
+        // CRITICAL-2 fix: Subtract storage deposit from available balance since it won't be usable
+        let available_balance = env::account_balance()
+            .as_yoctonear()
+            .saturating_sub(self.reserved_balance)
+            .saturating_sub(self.storage_deposit);
+        require!(
+            total_deposit <= available_balance,
+            "Insufficient available balance (pending transactions already reserved funds)"
+        );
+
+        // M-2 fix: Reserve balance for this transaction
+        self.reserved_balance = self.reserved_balance.saturating_add(total_deposit);
+
+        let tx_id = self.next_id();
+        let expiration = Self::expiration_from_hours(expiration_hours);
+
+        let tx = Transaction {
+            id: tx_id,
+            kind: ProposalKind::Executable { receiver_id: receiver_id.clone(), actions },
+            confirmations: vec![sender.clone()],
+            executed: false,
+            cancelled: false,
+            storage_depositor: sender.clone(),
+            expiration,
+        };
+
+        self.transactions.push(tx.clone());
+
+        // Emit event for off-chain indexing
+        MultisigEvent::TransactionSubmitted {
+            tx_id,
+            submitter: sender,
+            receiver_id,
+        }.emit();
+
+        // H-2 fix: Don't auto-execute even if threshold is 1 - require explicit execution
+        if self.num_confirmations == 1 {
+            MultisigEvent::TransactionReady {
+                tx_id,
+                confirmations: 1,
+            }.emit();
+        }
+
+        tx_id
+    }
+
+    /// Submit a text-only signaling proposal
+    /// Owners vote with confirm_transaction; at the threshold it passes and emits ProposalPassed
+    /// instead of executing. Requires the same 0.01 NEAR storage deposit, refunded when it
+    /// passes or is cancelled.
+    #[payable]
+    pub fn submit_signal(&mut self, title: String, description: String, expiration_hours: Option<u64>) -> u64 {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        let attached = env::attached_deposit().as_yoctonear();
+        require!(
+            attached >= self.storage_deposit,
+            format!("Must attach at least {} yoctoNEAR for storage", self.storage_deposit)
+        );
+
+        require!(!title.trim().is_empty(), "Title cannot be empty");
+        require!(title.len() <= MAX_TITLE_LEN, "Title too long (max 200 bytes)");
+        require!(description.len() <= MAX_DESCRIPTION_LEN, "Description too long (max 4KB)");
+
+        let tx_id = self.next_id();
+        let expiration = Self::expiration_from_hours(expiration_hours);
+
+        let mut tx = Transaction {
+            id: tx_id,
+            kind: ProposalKind::Signal { title: title.clone(), description },
+            confirmations: vec![sender.clone()],
+            executed: false,
+            cancelled: false,
+            storage_depositor: sender.clone(),
+            expiration,
+        };
+
+        MultisigEvent::SignalSubmitted {
+            tx_id,
+            submitter: sender,
+            title,
+        }.emit();
+
+        // A single required confirmation passes the signal right away
+        if self.num_confirmations == 1 {
+            self.pass_signal(&mut tx, 1);
+        }
+
+        self.transactions.push(tx);
+        tx_id
+    }
+
+    /// Confirm a pending transaction
+    pub fn confirm_transaction(&mut self, tx_id: u64) {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        // MEDIUM-2 fix: Removed u32::MAX check - get_tx() handles any u64 value
+
+        let mut tx = self.get_tx_or_panic(tx_id).clone();
+
+        require!(!tx.executed, "Already executed");
+        require!(!tx.cancelled, "Transaction cancelled");
+        require!(
+            !tx.confirmations.contains(&sender),
+            "Already confirmed by this owner"
+        );
+        // Signals pass on confirmation, so expiry is enforced here rather than at execution
+        if let Some(exp_time) = tx.expiration {
+            require!(env::block_timestamp() < exp_time, "Transaction expired");
+        }
+
+        tx.confirmations.push(sender.clone());
+        let confirmations_count = tx.confirmations.len() as u32;
+
+        // Emit confirmation event after state is persisted
+        MultisigEvent::TransactionConfirmed {
+            tx_id,
+            confirmer: sender,
+            confirmations: confirmations_count,
+        }.emit();
+
+        // H-2 fix: Don't auto-execute - emit ready event and require explicit execution.
+        // Signals have nothing to execute and pass immediately.
+        if confirmations_count >= self.num_confirmations {
+            if matches!(tx.kind, ProposalKind::Signal { .. }) {
+                self.pass_signal(&mut tx, confirmations_count);
+            } else {
+                MultisigEvent::TransactionReady {
+                    tx_id,
+                    confirmations: confirmations_count,
+                }.emit();
+            }
+        }
+
+        self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx);
+    }
+
+    /// Manually execute a fully-approved transaction (for retries after failure)
+    pub fn execute_transaction(&mut self, tx_id: u64) -> Promise {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        // MEDIUM-2 fix: Removed u32::MAX check - get_tx() handles any u64 value
+
+        let mut tx = self.get_tx_or_panic(tx_id).clone();
+
+        require!(
+            matches!(tx.kind, ProposalKind::Executable { .. }),
+            "Signal proposals pass at the threshold and cannot be executed"
+        );
+        require!(!tx.executed, "Already executed");
+        require!(!tx.cancelled, "Transaction cancelled");
+
+        // L-2 fix: Check if transaction has expired
+        if let Some(exp_time) = tx.expiration {
+            require!(
+                env::block_timestamp() < exp_time,
+                "Transaction expired"
+            );
+        }
+
+        require!(
+            tx.confirmations.len() as u32 >= self.num_confirmations,
+            "Not enough confirmations"
+        );
+
+        // Mark as executed
+        tx.executed = true;
+        self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx);
+
+        // Track pending callback to prevent cleanup corruption
+        self.pending_callbacks = self.pending_callbacks.saturating_add(1);
+
+        self.execute_transaction_internal(tx_id)
+    }
+
+    /// Internal: Execute transaction actions with proper promise chaining
+    fn execute_transaction_internal(&self, tx_id: u64) -> Promise {
+        let tx = self.get_tx_or_panic(tx_id);
+        let ProposalKind::Executable { receiver_id, actions } = &tx.kind else {
+            env::panic_str("Signal proposals cannot be executed");
+        };
+
+        // Security: Chain promises properly instead of creating separate ones (NH-3)
+        let mut promise = Promise::new(receiver_id.clone());
+
+        for action in actions {
+            match action {
+                Action::Transfer { amount } => {
+                    promise = promise.transfer(NearToken::from_yoctonear(*amount));
+                }
+                Action::FunctionCall {
+                    method_name,
+                    args,
+                    gas,
+                    deposit,
+                } => {
+                    promise = promise.function_call(
+                        method_name.clone(),
+                        args.clone(),
+                        NearToken::from_yoctonear(*deposit),
+                        Gas::from_gas(*gas),
+                    );
+                }
+            }
+        }
+
+        // Security: Attach callback to handle promise failures (NH-1)
+        // Callback gas is configurable (default 20 TGas) for flexibility with complex state updates
+        promise.then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Gas::from_gas(self.callback_gas))
+                .on_execute_callback(tx_id)
+        )
+    }
+
+    /// Cancel a pending transaction (only submitter can cancel)
+    /// Returns a Promise for the storage deposit refund
+    pub fn cancel_transaction(&mut self, tx_id: u64) -> Promise {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        // MEDIUM-2 fix: Removed u32::MAX check - get_tx() handles any u64 value
+
+        let mut tx = self.get_tx_or_panic(tx_id).clone();
+        require!(!tx.executed, "Already executed");
+        require!(!tx.cancelled, "Already cancelled");
+
+        // Only the submitter (first confirmer) can cancel
+        require!(
+            tx.confirmations.first() == Some(&sender),
+            "Only submitter can cancel"
+        );
+
+        tx.cancelled = true;
+        self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx.clone());
+
+        // M-2 fix: Release reserved balance when cancelling
+        let deposit = Self::calculate_transaction_deposit(&tx);
+        self.reserved_balance = self.reserved_balance.saturating_sub(deposit);
+
+        // Emit cancellation event
+        MultisigEvent::TransactionCancelled {
+            tx_id,
+            canceller: sender,
+        }.emit();
+
+        // Security (H-1 fix): Return refund promise instead of detaching
+        // If refund fails, the caller will be notified via promise failure
+        Promise::new(tx.storage_depositor.clone())
+            .transfer(NearToken::from_yoctonear(self.storage_deposit))
+    }
+
+    /// Update callback gas allocation (owner-only)
+    /// Allows adjusting gas for complex callback scenarios
+    pub fn set_callback_gas(&mut self, gas: u64) {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+        require!(gas >= 5_000_000_000_000, "Callback gas too low (min 5 TGas)");
+        require!(gas <= 100_000_000_000_000, "Callback gas too high (max 100 TGas)");
+
+        let old_gas = self.callback_gas;
+        self.callback_gas = gas;
+
+        // Emit configuration change event
+        MultisigEvent::CallbackGasChanged {
+            old_gas,
+            new_gas: gas,
+            changer: sender,
+        }.emit();
+    }
+
+    /// L-5 fix: Update storage deposit amount (owner-only)
+    pub fn set_storage_deposit(&mut self, deposit: u128) {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+        require!(deposit >= 1_000_000_000_000_000_000_000, "Storage deposit too low (min 0.001 NEAR)");
+        require!(deposit <= 1_000_000_000_000_000_000_000_000, "Storage deposit too high (max 1 NEAR)");
+
+        let old_deposit = self.storage_deposit;
+        self.storage_deposit = deposit;
+
+        MultisigEvent::StorageDepositChanged {
+            old_deposit,
+            new_deposit: deposit,
+            changer: sender,
+        }.emit();
+    }
+
+    pub fn get_storage_deposit(&self) -> u128 {
+        self.storage_deposit
+    }
+
+    /// Revoke your confirmation from a pending transaction
+    pub fn revoke_confirmation(&mut self, tx_id: u64) {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+
+        // MEDIUM-2 fix: Removed u32::MAX check - get_tx() handles any u64 value
+
+        let mut tx = self.get_tx_or_panic(tx_id).clone();
+        require!(!tx.executed, "Already executed");
+        require!(!tx.cancelled, "Transaction cancelled");
+
+        let pos = tx.confirmations.iter().position(|x| x == &sender);
+        require!(pos.is_some(), "Not confirmed by you");
+
+        tx.confirmations.remove(pos.unwrap());
+        let confirmations_count = tx.confirmations.len() as u32;
+        self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx);
+
+        // Emit revocation event
+        MultisigEvent::ConfirmationRevoked {
+            tx_id,
+            revoker: sender,
+            confirmations: confirmations_count,
+        }.emit();
+    }
+
+    /// Clean up old executed/cancelled transactions to reduce storage costs
+    /// WARNING: This is gas-expensive. Only removes transactions before the specified index.
+    /// Only executed or cancelled transactions can be removed (pending transactions are preserved).
+    /// BLOCKS if there are pending callbacks to prevent corruption.
+    /// Security: Processes max 100 transactions per call to prevent DoS
+    pub fn cleanup_old_transactions(&mut self, before_index: u64) -> u64 {
+        let sender = env::predecessor_account_id();
+        require!(self.owners.contains(&sender), "Not an owner");
+        require!(before_index <= u32::MAX as u64, "Index too large");
+
+        // Security: Prevent cleanup during pending callbacks to avoid index corruption
+        require!(
+            self.pending_callbacks == 0,
+            "Cannot cleanup while callbacks are pending"
+        );
+
+        let cleanup_end = (before_index as u32).min(self.transactions.len());
+        let mut removed_count = 0u64;
+
+        // Collect transactions to keep (avoid storage prefix collision)
+        let mut transactions_to_keep: Vec<Transaction> = Vec::new();
+
+        // Security: Limit iterations to prevent gas exhaustion DoS
+        let max_iterations = cleanup_end.min(MAX_CLEANUP_BATCH);
+
+        // Iterate transactions up to the batch limit
+        for i in 0..max_iterations {
+            if let Some(tx) = self.transactions.get(i) {
+                // Keep if: after cleanup range OR (in cleanup range but still pending)
+                if i >= cleanup_end || (!tx.executed && !tx.cancelled) {
+                    transactions_to_keep.push(tx.clone());
+                } else {
+                    removed_count += 1;
+                }
+            }
+        }
+
+        // Keep all transactions after the batch limit
+        for i in max_iterations..self.transactions.len() {
+            if let Some(tx) = self.transactions.get(i) {
+                transactions_to_keep.push(tx.clone());
+            }
+        }
+
+        // Clear and rebuild the vector to avoid storage corruption
+        self.transactions.clear();
+        for tx in transactions_to_keep {
+            self.transactions.push(tx);
+        }
+
+        // Emit cleanup event with transaction range context
+        MultisigEvent::TransactionsCleanedUp {
+            count: removed_count,
+            from_index: 0,
+            to_index: max_iterations as u64,
+            cleaner: sender,
+        }.emit();
+
+        removed_count
+    }
+
+    /// Security: Callback to handle promise execution results (NH-1)
+    /// If promise fails, mark transaction as not executed so it can be retried
+    #[private]
+    pub fn on_execute_callback(&mut self, tx_id: u64) {
+        // L-3 fix: Validate transaction exists and ID matches parameter
+        if let Some(tx) = self.get_tx(tx_id) {
+            require!(tx.id == tx_id, "Transaction ID mismatch in callback");
+        } else {
+            env::log_str(&format!("⚠️ Callback for non-existent transaction {}", tx_id));
+            return;
+        }
+
+        // Decrement pending callbacks counter
+        self.pending_callbacks = self.pending_callbacks.saturating_sub(1);
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                // Transaction executed successfully, already marked as executed
+                env::log_str(&format!("Transaction {} executed successfully", tx_id));
+
+                // M-2 fix: Release reserved balance after successful execution
+                if let Some(tx) = self.transactions.get(self.get_tx_index_or_panic(tx_id)) {
+                    let deposit = Self::calculate_transaction_deposit(tx);
+                    self.reserved_balance = self.reserved_balance.saturating_sub(deposit);
+                }
+
+                MultisigEvent::TransactionExecuted {
+                    tx_id,
+                    success: true,
+                }.emit();
+
+                // Security (H-1 fix): Refund storage deposit with callback to track failures
+                // Detached to prevent refund failures from affecting transaction execution result
+                if let Some(tx) = self.transactions.get(self.get_tx_index_or_panic(tx_id)) {
+                    Promise::new(tx.storage_depositor.clone())
+                        .transfer(NearToken::from_yoctonear(self.storage_deposit))
+                        .then(
+                            Self::ext(env::current_account_id())
+                                .with_static_gas(Gas::from_gas(5_000_000_000_000))
+                                .on_refund_callback(tx_id, tx.storage_depositor.clone())
+                        );
+                }
+            }
+            PromiseResult::Failed => {
+                // Promise failed - revert executed flag so transaction can be retried
+                env::log_str(&format!("Transaction {} failed, marking for retry", tx_id));
+                if let Some(tx) = self.transactions.get(self.get_tx_index_or_panic(tx_id)) {
+                    let mut tx_clone = tx.clone();
+                    tx_clone.executed = false;
+                    self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx_clone);
+                }
+                MultisigEvent::TransactionExecuted {
+                    tx_id,
+                    success: false,
+                }.emit();
+            }
+        }
+    }
+
+    /// Security (H-1 fix): Callback to track storage deposit refund results
+    /// Logs refund failures so users know if their deposit wasn't returned
+    #[private]
+    pub fn on_refund_callback(&mut self, tx_id: u64, recipient: AccountId) {
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                env::log_str(&format!("Storage deposit refund successful for tx {} to {}", tx_id, recipient));
+            }
+            PromiseResult::Failed => {
+                env::log_str(&format!("⚠️  Storage deposit refund FAILED for tx {} to {}. User may need to claim manually.", tx_id, recipient));
+                // Future enhancement: Store failed refunds in a claimable pool
+            }
+        }
+    }
+
+    // === View Methods ===
+
+    /// Get all owners
+    pub fn get_owners(&self) -> Vec<AccountId> {
+        self.owners.iter().cloned().collect()
+    }
+
+    /// Get confirmation threshold
+    pub fn get_num_confirmations(&self) -> u32 {
+        self.num_confirmations
+    }
+
+    /// Get a specific transaction
+    pub fn get_transaction(&self, tx_id: u64) -> Option<Transaction> {
+        self.get_tx(tx_id).cloned()
+    }
+
+    /// Get pending transactions (paginated to avoid gas exhaustion)
+    /// Security: Unbounded method removed - always use pagination to prevent DoS
+    pub fn get_pending_transactions_paginated(&self, from_index: u64, limit: u64) -> Vec<Transaction> {
+        let len = self.transactions.len() as u64;
+        let start = from_index.min(len);
+        let end = (start.saturating_add(limit)).min(len);
+
+        // HIGH-1 fix: Use direct index access instead of get_tx() to avoid O(n²) complexity
+        (start..end)
+            .filter_map(|i| {
+                let tx = self.transactions.get(i as u32)?;
+                if !tx.executed && !tx.cancelled {
+                    Some(tx.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Get all transactions (paginated)
+    pub fn get_transactions(&self, from_index: u64, limit: u64) -> Vec<Transaction> {
+        let len = self.transactions.len() as u64;
+        let start = from_index.min(len);
+        let end = (start.saturating_add(limit)).min(len);
+
+        // HIGH-1 fix: Use direct index access instead of get_tx() to avoid O(n²) complexity
+        (start..end)
+            .filter_map(|i| self.transactions.get(i as u32).cloned())
+            .collect()
+    }
+
+    /// Get total number of transactions
+    pub fn get_transaction_count(&self) -> u64 {
+        self.transactions.len() as u64
+    }
+
+    /// Check if account is an owner
+    pub fn is_owner(&self, account_id: AccountId) -> bool {
+        self.owners.contains(&account_id)
+    }
+
+    /// Check if account has confirmed a transaction
+    pub fn has_confirmed(&self, tx_id: u64, account_id: AccountId) -> bool {
+        if let Some(tx) = self.get_tx(tx_id) {
+            tx.confirmations.contains(&account_id)
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::mock::MockAction;
+    use near_sdk::test_utils::{accounts, get_created_receipts, get_logs, VMContextBuilder};
+    use near_sdk::{testing_env, VMContext};
+
+    fn get_context(predecessor: AccountId) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor)
+            .attached_deposit(NearToken::from_yoctonear(TRANSACTION_STORAGE_DEPOSIT))
+            .build()
+    }
+
+    fn setup(num_confirmations: u32) -> MultisigContract {
+        testing_env!(get_context(accounts(0)));
+        MultisigContract::new(vec![accounts(0), accounts(1), accounts(2)], num_confirmations)
+    }
+
+    fn submit_signal(contract: &mut MultisigContract, expiration_hours: Option<u64>) -> u64 {
+        contract.submit_signal(
+            "Adopt the treasury policy".to_string(),
+            "Signals support; moves no funds".to_string(),
+            expiration_hours,
+        )
+    }
+
+    #[test]
+    fn test_signal_passes_at_threshold() {
+        let mut contract = setup(2);
+        let tx_id = submit_signal(&mut contract, None);
+        assert!(!contract.get_transaction(tx_id).unwrap().executed);
+        assert_eq!(contract.reserved_balance, 0);
+
+        testing_env!(get_context(accounts(1)));
+        contract.confirm_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().executed);
+
+        let logs = get_logs();
+        assert!(logs.iter().any(|log| log.contains("proposal_passed")));
+        assert!(!logs.iter().any(|log| log.contains("transaction_ready")));
+
+        // The submitter gets the storage deposit back
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].receiver_id, accounts(0));
+        assert!(matches!(
+            receipts[0].actions[0],
+            MockAction::Transfer { deposit, .. }
+                if deposit == NearToken::from_yoctonear(TRANSACTION_STORAGE_DEPOSIT)
+        ));
+    }
+
+    #[test]
+    fn test_signal_passes_on_submit_with_single_confirmation() {
+        let mut contract = setup(1);
+        let tx_id = submit_signal(&mut contract, None);
+        assert!(contract.get_transaction(tx_id).unwrap().executed);
+        assert!(get_logs().iter().any(|log| log.contains("proposal_passed")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Signal proposals pass at the threshold and cannot be executed")]
+    fn test_signal_cannot_be_executed() {
+        let mut contract = setup(2);
+        let tx_id = submit_signal(&mut contract, None);
+        contract.execute_transaction(tx_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Transaction expired")]
+    fn test_expired_signal_cannot_pass() {
+        let mut contract = setup(2);
+        let tx_id = submit_signal(&mut contract, Some(1));
+
+        let mut context = get_context(accounts(1));
+        context.block_timestamp = 3_600_000_000_000;
+        testing_env!(context);
+        contract.confirm_transaction(tx_id);
+    }
+
+    #[test]
+    fn test_executable_proposal_still_executes() {
+        let mut contract = setup(2);
+        let actions = vec![Action::Transfer { amount: 1000 }];
+        let tx_id = contract.submit_transaction(accounts(3), actions, None);
+
+        testing_env!(get_context(accounts(1)));
+        contract.confirm_transaction(tx_id);
+        assert!(get_logs().iter().any(|log| log.contains("transaction_ready")));
+
+        contract.execute_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().executed);
+    }
+}
+"#;
+
+const GITHUB_ACTIONS_WORKFLOW: &str = r#"name: Release Contract
+
+on:
+  push:
+    tags:
+      - 'v[0-9]+*'
+
+permissions:
+  contents: write
+
+jobs:
+  release:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+
+      - name: Install Rust
+        uses: actions-rs/toolchain@v1
+        with:
+          toolchain: 1.86.0
+          target: wasm32-unknown-unknown
+          override: true
+
+      - name: Install cargo-near
+        run: |
+          curl --proto '=https' --tlsv1.2 -LsSf https://github.com/near/cargo-near/releases/download/cargo-near-v0.18.0/cargo-near-installer.sh | sh
+
+      - name: Build contract
+        run: cargo near build non-reproducible-wasm
+
+      - name: Generate verification artifacts
+        run: |
+          mkdir -p release
+          cp target/near/*.wasm release/
+          cd release
+          sha256sum -b *.wasm > SHA256SUMS
+
+      - name: Create GitHub Release
+        uses: softprops/action-gh-release@v1
+        with:
+          files: |
+            release/*.wasm
+            release/SHA256SUMS
+          generate_release_notes: true
+          body: |
+            ## Verification
+
+            Download and verify the contract:
+
+            ```bash
+            wget https://github.com/${{ github.repository }}/releases/download/${{ github.ref_name }}/SHA256SUMS
+            wget https://github.com/${{ github.repository }}/releases/download/${{ github.ref_name }}/*.wasm
+            sha256sum -c SHA256SUMS
+            ```
+
+            **Build Info**:
+            - Rust: 1.86.0
+            - cargo-near: 0.18.0
+            - near-sdk: 5.24.0
+            - Commit: ${{ github.sha }}
+"#;
+
+pub fn run(project_name: &str, template: &str, sdk_version: &str, json: bool) -> Result<()> {
+    // L-4 fix: Validate project name to prevent path traversal and filesystem issues
+    if project_name.is_empty() {
+        anyhow::bail!("Project name cannot be empty");
+    }
+    if project_name.contains('/') || project_name.contains('\\') {
+        anyhow::bail!("Project name cannot contain path separators");
+    }
+    if project_name.starts_with('.') || project_name.starts_with('-') {
+        anyhow::bail!("Project name cannot start with '.' or '-'");
+    }
+    if !project_name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        anyhow::bail!(
+            "Project name can only contain alphanumeric characters, hyphens, and underscores"
+        );
+    }
+
+    let (build_image, build_image_digest) = build_image_for(sdk_version)?;
+
+    let project_path = Path::new(project_name);
+
+    if project_path.exists() {
+        anyhow::bail!("Directory '{}' already exists", project_name);
+    }
+
+    // Select template
+    let (lib_template, template_description) = match template {
+        "basic" => (
+            BASIC_TEMPLATE_LIB,
+            "Basic M-of-N multisig with security hardening",
+        ),
+        "timelock" => (
+            TIMELOCK_TEMPLATE_LIB,
+            "Timelock multisig with delayed execution and security hardening",
+        ),
+        "weighted" => (
+            WEIGHTED_TEMPLATE_LIB,
+            "Weighted voting multisig with security hardening",
+        ),
+        "dao" => (
+            DAO_TEMPLATE_LIB,
+            "DAO proposals with text signaling votes and security hardening",
+        ),
+        _ => anyhow::bail!(
+            "Unknown template: {}. Available: basic, timelock, weighted, dao",
+            template
+        ),
+    };
+
+    if !json {
+        println!(
+            "Creating multisig project: {} ({})",
+            project_name, template_description
+        );
+    }
+
+    // Create directory structure
+    fs::create_dir_all(project_path.join("src"))?;
+    fs::create_dir_all(project_path.join(".github/workflows"))?;
+
+    // Write Cargo.toml
+    let cargo_toml = BASIC_TEMPLATE_CARGO
+        .replace("{{project_name}}", project_name)
+        .replace("{{sdk_version}}", sdk_version)
+        .replace("{{build_image}}", build_image)
+        .replace("{{build_image_digest}}", build_image_digest);
+    fs::write(project_path.join("Cargo.toml"), cargo_toml)?;
+
+    // Write src/lib.rs
+    fs::write(project_path.join("src/lib.rs"), lib_template)?;
+
+    // Write GitHub Actions workflow
+    fs::write(
+        project_path.join(".github/workflows/release.yml"),
+        GITHUB_ACTIONS_WORKFLOW,
+    )?;
+
+    if json {
+        let output = serde_json::json!({
+            "project": project_name,
+            "template": template,
+            "sdk_version": sdk_version,
+            "files": [
+                "Cargo.toml",
+                "src/lib.rs",
+                ".github/workflows/release.yml",
+            ],
+            "success": true,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("✓ Created {}/", project_name);
+    println!("✓ Created {}/Cargo.toml", project_name);
+    println!("✓ Created {}/src/lib.rs", project_name);
+    println!("✓ Created {}/.github/workflows/release.yml", project_name);
+    println!("\nNext steps:");
+    println!("  cd {}", project_name);
+    println!("  git init && git add . && git commit -m 'Initial commit'");
+    println!("  git tag v0.1.0 && git push --tags  # Triggers auto-release");
+    println!("  OR: near-multisig build  # For local development");
+    println!(
+        "\n✓ All security fixes applied (callback handling, overflow protection, input validation)"
+    );
+
+    Ok(())
+}
+
+/// Looks up the pinned reproducible build image for a near-sdk version
+fn build_image_for(sdk_version: &str) -> Result<(&'static str, &'static str)> {
+    let parts: Vec<&str> = sdk_version.split('.').collect();
+    if parts.len() != 3
+        || parts
+            .iter()
+            .any(|p| p.is_empty() || !p.chars().all(|c| c.is_ascii_digit()))
+    {
+        anyhow::bail!(
+            "Invalid near-sdk version: {}. Expected MAJOR.MINOR.PATCH, e.g. {}",
+            sdk_version,
+            DEFAULT_SDK_VERSION
+        );
+    }
+
+    SDK_BUILD_IMAGES
+        .iter()
+        .find(|(version, _, _)| *version == sdk_version)
+        .map(|(_, image, digest)| (*image, *digest))
+        .ok_or_else(|| {
+            let supported: Vec<&str> = SDK_BUILD_IMAGES.iter().map(|(v, _, _)| *v).collect();
+            anyhow::anyhow!(
+                "No reproducible build image is pinned for near-sdk {}. Supported versions: {}",
+                sdk_version,
+                supported.join(", ")
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_template_matches_compiled_copy() {
+        // templates/basic/standalone builds this exact source and runs its unit tests,
+        // including the `expiration_hours too large` overflow check
+        assert_eq!(
+            BASIC_TEMPLATE_LIB,
+            include_str!("../../../templates/basic/standalone/src/lib.rs")
+        );
+    }
+
+    #[test]
+    fn test_dao_template_matches_compiled_copy() {
+        // templates/dao/contract builds this exact source; its unit tests cover signals
+        // passing at the threshold, emitting ProposalPassed and refunding the deposit
+        assert_eq!(
+            DAO_TEMPLATE_LIB,
+            include_str!("../../../templates/dao/contract/src/lib.rs")
+        );
+    }
 }
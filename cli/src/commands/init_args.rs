@@ -0,0 +1,229 @@
+use anyhow::{Context, Result};
+use near_account_id::AccountId;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+/// Mirrors the templates' MAX_OWNERS
+const MAX_OWNERS: usize = 50;
+
+/// Timelock bounds enforced by the timelock template (seconds)
+const MIN_TIMELOCK_SECS: u64 = 60;
+const MAX_TIMELOCK_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Values given on the command line; anything missing is prompted for on a terminal
+pub struct InitArgsOptions {
+    pub template: Option<String>,
+    pub owners: Vec<String>,
+    pub threshold: Option<u32>,
+    pub timelock_seconds: Option<u64>,
+    pub output: String,
+}
+
+pub fn run(options: InitArgsOptions, json: bool) -> Result<()> {
+    let interactive = io::stdin().is_terminal() && !json;
+
+    let template = match options.template {
+        Some(template) => template,
+        None => prompt(
+            interactive,
+            "Template (basic, timelock, weighted, dao)",
+            "--template",
+        )?,
+    };
+
+    let owners = if options.owners.is_empty() {
+        let hint = if template == "weighted" {
+            "Owners as account:weight, comma-separated"
+        } else {
+            "Owners, comma-separated"
+        };
+        prompt(interactive, hint, "--owner")?
+            .split(',')
+            .map(|owner| owner.trim().to_string())
+            .filter(|owner| !owner.is_empty())
+            .collect()
+    } else {
+        options.owners
+    };
+
+    let threshold = match options.threshold {
+        Some(threshold) => threshold,
+        None => {
+            let label = if template == "weighted" {
+                "Approval threshold (total weight)"
+            } else {
+                "Confirmations required"
+            };
+            parse_number(&prompt(interactive, label, "--threshold")?, "threshold")?
+        }
+    };
+
+    let args = match template.as_str() {
+        "basic" | "dao" => basic_args(&owners, threshold)?,
+        "timelock" => {
+            let seconds = match options.timelock_seconds {
+                Some(seconds) => seconds,
+                None => parse_number(
+                    &prompt(
+                        interactive,
+                        "Timelock duration in seconds",
+                        "--timelock-seconds",
+                    )?,
+                    "timelock duration",
+                )?,
+            };
+            timelock_args(&owners, threshold, seconds)?
+        }
+        "weighted" => weighted_args(&owners, threshold)?,
+        _ => anyhow::bail!(
+            "Unknown template: {}. Available: basic, timelock, weighted, dao",
+            template
+        ),
+    };
+
+    fs::write(&options.output, serde_json::to_string_pretty(&args)?)
+        .with_context(|| format!("Failed to write {}", options.output))?;
+
+    if json {
+        let output = json!({
+            "template": template,
+            "path": options.output,
+            "args": args,
+            "success": true,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    println!("✓ Wrote {} ({} template)", options.output, template);
+    println!("\nInitialize with:");
+    println!(
+        "  near call <your-multisig> new \"$(cat {})\" --accountId <your-multisig>",
+        options.output
+    );
+
+    Ok(())
+}
+
+/// Reads one line from stdin, or explains which flag to pass when not on a terminal
+fn prompt(interactive: bool, label: &str, flag: &str) -> Result<String> {
+    if !interactive {
+        anyhow::bail!("Missing {} (no terminal to prompt on)", flag);
+    }
+    print!("{}: ", label);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, what: &str) -> Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid {}: '{}'", what, value))
+}
+
+/// Parses and checks an owner list the way the templates' `new` does
+fn parse_owners(owners: &[String]) -> Result<Vec<AccountId>> {
+    if owners.is_empty() {
+        anyhow::bail!("Need at least one owner");
+    }
+    if owners.len() > MAX_OWNERS {
+        anyhow::bail!("Too many owners (max {})", MAX_OWNERS);
+    }
+
+    let mut seen = HashSet::new();
+    owners
+        .iter()
+        .map(|owner| {
+            let account: AccountId = owner
+                .parse()
+                .with_context(|| format!("Invalid account ID: '{}'", owner))?;
+            if !seen.insert(account.clone()) {
+                anyhow::bail!("Duplicate owner: {}", account);
+            }
+            Ok(account)
+        })
+        .collect()
+}
+
+fn check_confirmations(num_confirmations: u32, owner_count: usize) -> Result<()> {
+    if num_confirmations == 0 || num_confirmations as usize > owner_count {
+        anyhow::bail!(
+            "Invalid confirmation threshold: {} (must be between 1 and {})",
+            num_confirmations,
+            owner_count
+        );
+    }
+    Ok(())
+}
+
+fn basic_args(owners: &[String], num_confirmations: u32) -> Result<Value> {
+    let owners = parse_owners(owners)?;
+    check_confirmations(num_confirmations, owners.len())?;
+    Ok(json!({
+        "owners": owners,
+        "num_confirmations": num_confirmations,
+    }))
+}
+
+fn timelock_args(owners: &[String], num_confirmations: u32, seconds: u64) -> Result<Value> {
+    let owners = parse_owners(owners)?;
+    check_confirmations(num_confirmations, owners.len())?;
+    if seconds < MIN_TIMELOCK_SECS {
+        anyhow::bail!("Timelock too short (min 1 minute)");
+    }
+    if seconds > MAX_TIMELOCK_SECS {
+        anyhow::bail!("Timelock too long (max 30 days)");
+    }
+    Ok(json!({
+        "owners": owners,
+        "num_confirmations": num_confirmations,
+        // The contract takes nanoseconds
+        "timelock_duration": seconds * 1_000_000_000,
+    }))
+}
+
+fn weighted_args(owners: &[String], approval_threshold: u32) -> Result<Value> {
+    let mut accounts = Vec::new();
+    let mut weights = Vec::new();
+    for owner in owners {
+        let (account, weight) = owner.rsplit_once(':').with_context(|| {
+            format!(
+                "Weighted owners need a weight: '{}' (account:weight)",
+                owner
+            )
+        })?;
+        let weight: u32 = parse_number(weight, "weight")?;
+        if weight == 0 {
+            anyhow::bail!("Weight must be positive: '{}'", owner);
+        }
+        accounts.push(account.to_string());
+        weights.push(weight);
+    }
+    let accounts = parse_owners(&accounts)?;
+
+    let total_weight = weights
+        .iter()
+        .try_fold(0u32, |acc, w| acc.checked_add(*w))
+        .context("Total weight overflow")?;
+    if approval_threshold == 0 {
+        anyhow::bail!("Threshold must be positive");
+    }
+    if approval_threshold > total_weight {
+        anyhow::bail!(
+            "Threshold exceeds total weight ({} > {})",
+            approval_threshold,
+            total_weight
+        );
+    }
+
+    let owners_with_weights: Vec<(AccountId, u32)> = accounts.into_iter().zip(weights).collect();
+    Ok(json!({
+        "owners_with_weights": owners_with_weights,
+        "approval_threshold": approval_threshold,
+    }))
+}
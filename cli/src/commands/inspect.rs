@@ -0,0 +1,76 @@
+use anyhow::{Context, Result};
+use near_multisig_lib::client::{MultisigClient, MultisigSummary, OwnerInfo};
+
+pub fn run(account: &str, network: &str, json: bool) -> Result<()> {
+    let account_id = account
+        .parse()
+        .with_context(|| format!("Invalid account ID: {}", account))?;
+    let client = MultisigClient::for_network(network)?;
+
+    if !json {
+        println!("Inspecting {} on {}...", account, network);
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let summary = runtime.block_on(client.get_summary(&account_id))?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        print_summary(&summary);
+    }
+
+    Ok(())
+}
+
+fn print_summary(summary: &MultisigSummary) {
+    println!("\nMultisig: {}", summary.account_id);
+    println!("  Owners ({}):", summary.owners.len());
+    for owner in &summary.owners {
+        println!("    - {}", format_owner(owner));
+    }
+
+    if let Some(threshold) = summary.threshold {
+        println!("  Threshold:       {}", threshold);
+    }
+    if let Some(storage_deposit) = summary.storage_deposit {
+        println!("  Storage deposit: {}", format_near(storage_deposit));
+    }
+    if let Some(callback_gas) = summary.callback_gas {
+        println!(
+            "  Callback gas:    {} TGas",
+            callback_gas / 1_000_000_000_000
+        );
+    }
+    if let Some(state_version) = summary.state_version {
+        println!("  State version:   {}", state_version);
+    }
+    if let Some(binary_version) = &summary.binary_version {
+        println!("  Binary version:  {}", binary_version);
+    }
+
+    println!("\nQueue:");
+    println!("  Transactions in storage: {}", summary.transaction_count);
+    println!("  Pending:                 {}", summary.pending_count);
+}
+
+fn format_owner(owner: &OwnerInfo) -> String {
+    match owner.weight {
+        Some(weight) => format!("{} (weight {})", owner.account_id, weight),
+        None => owner.account_id.clone(),
+    }
+}
+
+fn format_near(yocto: u128) -> String {
+    const YOCTO_PER_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+    let whole = yocto / YOCTO_PER_NEAR;
+    let fraction = format!("{:024}", yocto % YOCTO_PER_NEAR);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{} NEAR", whole)
+    } else {
+        format!("{}.{} NEAR", whole, fraction)
+    }
+}
@@ -1,3 +1,6 @@
 pub mod build;
 pub mod init;
+pub mod init_args;
+pub mod inspect;
 pub mod verify;
+pub mod watch;
@@ -1,9 +1,22 @@
 use anyhow::Result;
-use near_multisig_lib::checksum::SHA256SUMS;
+use near_multisig_lib::build_manifest::{
+    parse_public_key, BuildManifest, SignedManifest, SIGNATURE_FILENAME,
+};
+use near_multisig_lib::checksum::{ArtifactKind, VerificationResult, SHA256SUMS};
 use std::path::Path;
 
-pub fn run(release_dir: &str, reproduce: bool) -> Result<()> {
+pub fn run(
+    release_dir: &str,
+    reproduce: bool,
+    wasm_only_strict: bool,
+    manifest_only: bool,
+    pubkey: Option<&str>,
+    json: bool,
+) -> Result<()> {
     let release_path = Path::new(release_dir);
+    let signature = pubkey
+        .map(|key| check_signature(release_path, key))
+        .transpose();
 
     // Read SHA256SUMS
     let checksums_file = release_path.join("SHA256SUMS");
@@ -13,6 +26,31 @@ pub fn run(release_dir: &str, reproduce: bool) -> Result<()> {
 
     let checksums = SHA256SUMS::from_file(&checksums_file)?;
 
+    if manifest_only {
+        return verify_manifest(release_path, &checksums, signature, json);
+    }
+
+    if json {
+        let result = checksums.verify(release_path)?;
+        let success = is_success(&result, wasm_only_strict);
+        let mut output = serde_json::to_value(&result)?;
+        output["success"] = success.into();
+        output["wasm_success"] = result.is_success_for(ArtifactKind::Wasm).into();
+        if pubkey.is_some() {
+            output["signature_verified"] = signature.is_ok().into();
+            if let Err(e) = &signature {
+                output["signature_error"] = format!("{:#}", e).into();
+            }
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+
+        if !success {
+            anyhow::bail!("Verification failed");
+        }
+        signature?;
+        return Ok(());
+    }
+
     println!("Verifying checksums...");
     let result = checksums.verify(release_path)?;
 
@@ -21,19 +59,31 @@ pub fn run(release_dir: &str, reproduce: bool) -> Result<()> {
     }
 
     for mismatch in &result.mismatch {
-        println!("✗ {} (checksum mismatch)", mismatch.filename);
+        println!(
+            "{} {} (checksum mismatch)",
+            marker(&mismatch.filename, wasm_only_strict),
+            mismatch.filename
+        );
         println!("  Expected: {}", mismatch.expected);
         println!("  Actual:   {}", mismatch.actual);
     }
 
     for missing in &result.missing {
-        println!("✗ {} (file missing)", missing);
+        println!(
+            "{} {} (file missing)",
+            marker(missing, wasm_only_strict),
+            missing
+        );
     }
 
-    if !result.is_success() {
+    if !is_success(&result, wasm_only_strict) {
         anyhow::bail!("Verification failed");
     }
 
+    if let Some(key) = signature? {
+        println!("✓ {} (signed by {})", SIGNATURE_FILENAME, key);
+    }
+
     if reproduce {
         println!("\nReproducibility testing not yet implemented");
         // TODO: Clone repo, checkout commit, rebuild, compare
@@ -41,3 +91,81 @@ pub fn run(release_dir: &str, reproduce: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// `--manifest-only`: checks build-manifest.json against SHA256SUMS, leaving the wasm unread
+fn verify_manifest(
+    release_path: &Path,
+    checksums: &SHA256SUMS,
+    signature: Result<Option<String>>,
+    json: bool,
+) -> Result<()> {
+    let manifest = BuildManifest::from_file(&release_path.join("build-manifest.json"))?;
+    let issues = manifest.consistency_issues(checksums);
+
+    if json {
+        let mut output = serde_json::json!({
+            "manifest_only": true,
+            "filename": manifest.output.filename,
+            "wasm_hash": manifest.output.wasm_hash,
+            "issues": issues,
+            "success": issues.is_empty(),
+        });
+        if let Err(e) = &signature {
+            output["signature_verified"] = false.into();
+            output["signature_error"] = format!("{:#}", e).into();
+        } else if let Ok(Some(_)) = &signature {
+            output["signature_verified"] = true.into();
+        }
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!("Cross-checking build-manifest.json against SHA256SUMS...");
+        if issues.is_empty() {
+            println!(
+                "✓ {} ({} matches SHA256SUMS)",
+                manifest.output.filename, manifest.output.wasm_hash
+            );
+        }
+        for issue in &issues {
+            println!("✗ {}", issue);
+        }
+    }
+
+    if !issues.is_empty() {
+        anyhow::bail!("Verification failed");
+    }
+
+    if let Some(key) = signature? {
+        if !json {
+            println!("✓ {} (signed by {})", SIGNATURE_FILENAME, key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks build-manifest.sig against the manifest and `pubkey`, returning the signer
+fn check_signature(release_path: &Path, pubkey: &str) -> Result<String> {
+    let expected = parse_public_key(pubkey)?;
+    let manifest = BuildManifest::from_file(&release_path.join("build-manifest.json"))?;
+    let signed = SignedManifest::from_file(&release_path.join(SIGNATURE_FILENAME))?;
+    signed.verify(&manifest, &expected)?;
+    Ok(signed.public_key)
+}
+
+/// In `--wasm-only-strict` mode only wasm problems fail verification
+fn is_success(result: &VerificationResult, wasm_only_strict: bool) -> bool {
+    if wasm_only_strict {
+        result.is_success_for(ArtifactKind::Wasm)
+    } else {
+        result.is_success()
+    }
+}
+
+/// Non-wasm problems are downgraded to warnings in `--wasm-only-strict` mode
+fn marker(filename: &str, wasm_only_strict: bool) -> &'static str {
+    if wasm_only_strict && ArtifactKind::from_filename(filename) != ArtifactKind::Wasm {
+        "⚠"
+    } else {
+        "✗"
+    }
+}
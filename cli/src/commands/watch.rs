@@ -0,0 +1,137 @@
+use anyhow::{Context, Result};
+use near_multisig_lib::events::EventLog;
+use near_multisig_lib::rpc::RpcClient;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// Delay between polls once caught up with the chain head
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What to print and for which contract
+struct Filter<'a> {
+    account: &'a str,
+    standard: &'a str,
+    event: Option<&'a str>,
+    json: bool,
+}
+
+pub fn run(
+    account: &str,
+    network: &str,
+    standard: &str,
+    event: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let rpc = RpcClient::for_network(network)?;
+    let filter = Filter {
+        account,
+        standard,
+        event,
+        json,
+    };
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+
+    let mut next_height = runtime.block_on(latest_height(&rpc))?;
+    if !json {
+        println!(
+            "Watching {} on {} from block {} (Ctrl+C to stop)...",
+            account, network, next_height
+        );
+    }
+
+    loop {
+        let latest = runtime.block_on(latest_height(&rpc))?;
+        while next_height <= latest {
+            runtime.block_on(scan_block(&rpc, next_height, &filter))?;
+            next_height += 1;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+async fn latest_height(rpc: &RpcClient) -> Result<u64> {
+    let block = rpc.call("block", json!({ "finality": "final" })).await?;
+    block["header"]["height"]
+        .as_u64()
+        .context("Block response missing height")
+}
+
+/// Prints matching events from transactions sent to the account in one block
+async fn scan_block(rpc: &RpcClient, height: u64, filter: &Filter<'_>) -> Result<()> {
+    let response = rpc.request("block", json!({ "block_id": height })).await?;
+    let Some(block) = response.get("result") else {
+        // Skipped heights have no block
+        if response.to_string().contains("UNKNOWN_BLOCK") {
+            return Ok(());
+        }
+        anyhow::bail!("RPC error fetching block {}: {}", height, response["error"]);
+    };
+
+    let chunks = block["chunks"].as_array().cloned().unwrap_or_default();
+    // Chunks not produced at this height are repeats from an earlier block
+    for chunk in chunks
+        .iter()
+        .filter(|c| c["height_included"].as_u64() == Some(height))
+    {
+        let chunk = rpc
+            .call("chunk", json!({ "chunk_id": chunk["chunk_hash"] }))
+            .await?;
+        let transactions = chunk["transactions"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+
+        for tx in transactions
+            .iter()
+            .filter(|tx| tx["receiver_id"].as_str() == Some(filter.account))
+        {
+            let status = rpc
+                .call(
+                    "tx",
+                    json!({
+                        "tx_hash": tx["hash"],
+                        "sender_account_id": tx["signer_id"],
+                        "wait_until": "EXECUTED",
+                    }),
+                )
+                .await?;
+            print_events(&status, height, tx["hash"].as_str().unwrap_or("?"), filter);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_events(status: &Value, height: u64, tx_hash: &str, filter: &Filter<'_>) {
+    let outcomes = status["receipts_outcome"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+
+    // Callbacks run on the multisig too, so its receipts cover the whole execution
+    let events = outcomes
+        .iter()
+        .filter(|o| o["outcome"]["executor_id"].as_str() == Some(filter.account))
+        .flat_map(|o| o["outcome"]["logs"].as_array().cloned().unwrap_or_default())
+        .filter_map(|log| EventLog::parse(log.as_str()?))
+        .filter(|e| e.standard == filter.standard)
+        .filter(|e| filter.event.is_none_or(|name| e.is(name)));
+
+    for event in events {
+        if filter.json {
+            let line = json!({
+                "block_height": height,
+                "tx_hash": tx_hash,
+                "event": event.event,
+                "version": event.version,
+                "data": event.data,
+            });
+            println!("{}", line);
+        } else {
+            println!("[#{}] {} {}", height, event.event, event.data);
+        }
+    }
+}
@@ -7,6 +7,10 @@ mod commands;
 #[command(name = "near-multisig")]
 #[command(about = "Toolkit for building verified NEAR multisig contracts")]
 struct Cli {
+    /// Emit machine-readable JSON instead of human-readable output
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -18,15 +22,63 @@ enum Commands {
         /// Project name
         name: String,
 
-        /// Template to use: basic, timelock, weighted
+        /// Template to use: basic, timelock, weighted, dao
         #[arg(short, long, default_value = "basic")]
         template: String,
+
+        /// near-sdk version for the generated project (must have a pinned build image)
+        #[arg(long, default_value = commands::init::DEFAULT_SDK_VERSION)]
+        sdk_version: String,
+    },
+    /// Write a validated init-args.json for a template's `new` method
+    InitArgs {
+        /// Template to use: basic, timelock, weighted, dao (prompted if omitted)
+        #[arg(short, long)]
+        template: Option<String>,
+
+        /// Owner account; `account:weight` for weighted. Repeatable (prompted if omitted)
+        #[arg(long = "owner")]
+        owners: Vec<String>,
+
+        /// Confirmations required, or total weight for weighted (prompted if omitted)
+        #[arg(long)]
+        threshold: Option<u32>,
+
+        /// Timelock duration in seconds, timelock template only (prompted if omitted)
+        #[arg(long)]
+        timelock_seconds: Option<u64>,
+
+        /// Where to write the args
+        #[arg(short, long, default_value = "init-args.json")]
+        output: String,
     },
     /// Build reproducible WASM with verification artifacts
     Build {
         /// Output directory for release artifacts
         #[arg(long, default_value = "release")]
         release_dir: String,
+
+        /// Record sha256 hashes of the source files in build-manifest.json
+        #[arg(long)]
+        source_hashes: bool,
+
+        /// File or directory (its .rs files) to hash; repeatable [default: src, Cargo.toml, Cargo.lock]
+        #[arg(long = "source-path", requires = "source_hashes")]
+        source_paths: Vec<String>,
+
+        /// Delete .wasm, SHA256SUMS and build-manifest.json left in the release dir by an
+        /// earlier build (otherwise the build refuses to run)
+        #[arg(long)]
+        clean: bool,
+
+        /// Sign build-manifest.json with this ed25519 key (NEAR credentials JSON or
+        /// `ed25519:<base58>` secret), writing build-manifest.sig
+        #[arg(long, value_name = "KEY_FILE")]
+        sign: Option<String>,
+
+        /// Fail if the built wasm is larger than this many bytes
+        #[arg(long, value_name = "BYTES")]
+        max_wasm_size: Option<u64>,
     },
     /// Verify checksums and reproducibility
     Verify {
@@ -36,6 +88,46 @@ enum Commands {
         /// Perform full reproducibility test
         #[arg(long)]
         reproduce: bool,
+
+        /// Fail only on wasm mismatches; report JSON metadata mismatches as warnings
+        #[arg(long)]
+        wasm_only_strict: bool,
+
+        /// Cross-check build-manifest.json against SHA256SUMS without reading the wasm
+        #[arg(long, conflicts_with_all = ["reproduce", "wasm_only_strict"])]
+        manifest_only: bool,
+
+        /// Require build-manifest.sig to be a valid signature by this `ed25519:<base58>` key
+        #[arg(long)]
+        pubkey: Option<String>,
+    },
+    /// Fetch and summarize a deployed multisig's state
+    Inspect {
+        /// Account ID of the deployed multisig contract
+        #[arg(long)]
+        account: String,
+
+        /// Network to query: mainnet, testnet, or an RPC URL
+        #[arg(long, default_value = "testnet")]
+        network: String,
+    },
+    /// Tail a deployed multisig's events as they happen
+    Watch {
+        /// Account ID of the deployed multisig contract
+        #[arg(long)]
+        account: String,
+
+        /// Network to poll: mainnet, testnet, or an RPC URL
+        #[arg(long, default_value = "testnet")]
+        network: String,
+
+        /// Only show this event, e.g. TransactionReady
+        #[arg(long)]
+        event: Option<String>,
+
+        /// NEP-297 standard name the contract emits events under
+        #[arg(long, default_value = "multisig")]
+        standard: String,
     },
 }
 
@@ -43,11 +135,65 @@ fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init { name, template } => commands::init::run(&name, &template),
-        Commands::Build { release_dir } => commands::build::run(&release_dir),
+        Commands::Init {
+            name,
+            template,
+            sdk_version,
+        } => commands::init::run(&name, &template, &sdk_version, cli.json),
+        Commands::InitArgs {
+            template,
+            owners,
+            threshold,
+            timelock_seconds,
+            output,
+        } => commands::init_args::run(
+            commands::init_args::InitArgsOptions {
+                template,
+                owners,
+                threshold,
+                timelock_seconds,
+                output,
+            },
+            cli.json,
+        ),
+        Commands::Build {
+            release_dir,
+            source_hashes,
+            source_paths,
+            clean,
+            sign,
+            max_wasm_size,
+        } => commands::build::run(
+            &release_dir,
+            source_hashes,
+            source_paths,
+            clean,
+            sign.as_deref(),
+            max_wasm_size,
+            cli.json,
+        ),
         Commands::Verify {
             release_dir,
             reproduce,
-        } => commands::verify::run(&release_dir, reproduce),
+            wasm_only_strict,
+            manifest_only,
+            pubkey,
+        } => commands::verify::run(
+            &release_dir,
+            reproduce,
+            wasm_only_strict,
+            manifest_only,
+            pubkey.as_deref(),
+            cli.json,
+        ),
+        Commands::Inspect { account, network } => {
+            commands::inspect::run(&account, &network, cli.json)
+        }
+        Commands::Watch {
+            account,
+            network,
+            event,
+            standard,
+        } => commands::watch::run(&account, &network, &standard, event.as_deref(), cli.json),
     }
 }
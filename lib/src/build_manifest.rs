@@ -1,7 +1,21 @@
-use anyhow::Result;
+use crate::checksum::SHA256SUMS;
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Hash algorithms accepted in `output.wasm_hash`, with their hex digest length
+const HASH_ALGORITHMS: &[(&str, usize)] = &[("sha256", 64)];
+
+/// Paths hashed into `source.source_files` unless the caller picks its own set
+pub const DEFAULT_SOURCE_PATHS: &[&str] = &["src", "Cargo.toml", "Cargo.lock"];
+
+/// Env var `near-multisig build` passes the git commit in, for the contract to embed
+pub const GIT_COMMIT_ENV: &str = "MULTISIG_GIT_COMMIT";
+
+/// Detached signature file written next to build-manifest.json
+pub const SIGNATURE_FILENAME: &str = "build-manifest.sig";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BuildManifest {
@@ -18,6 +32,9 @@ pub struct SourceInfo {
     pub commit: String,
     pub tag: Option<String>,
     pub path: String,
+    /// (path, sha256) of the sources that went into the build; empty unless requested
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_files: Vec<(String, String)>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -41,6 +58,9 @@ pub struct OutputInfo {
     pub wasm_hash: String, // "sha256:abc123..."
     pub wasm_size: u64,
     pub filename: String,
+    /// What the contract's `get_binary_version` reports; None if STATE_VERSION wasn't found
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binary_version: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -55,6 +75,13 @@ pub struct Standard {
     pub version: String,
 }
 
+/// Ed25519 attestation of a manifest; keys and signature use NEAR's `ed25519:<base58>` form
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedManifest {
+    pub public_key: String,
+    pub signature: String,
+}
+
 impl BuildManifest {
     pub fn generate(wasm_path: &PathBuf) -> Result<Self> {
         let wasm_bytes = fs::read(wasm_path)?;
@@ -63,6 +90,8 @@ impl BuildManifest {
 
         // Try to get git info, fallback to placeholders
         let (repository, commit, tag) = get_git_info();
+        let binary_version =
+            find_state_version(Path::new("src")).map(|state| binary_version(&commit, state));
 
         Ok(Self {
             version: "1.0.0".to_string(),
@@ -71,6 +100,7 @@ impl BuildManifest {
                 commit,
                 tag,
                 path: ".".to_string(),
+                source_files: Vec::new(),
             },
             build: BuildInfo {
                 builder_image: "sourcescan/cargo-near:0.18.0-rust-1.86.0".to_string(),
@@ -95,6 +125,7 @@ impl BuildManifest {
                 wasm_hash,
                 wasm_size,
                 filename: wasm_path.file_name().unwrap().to_string_lossy().to_string(),
+                binary_version,
             },
             metadata: MetadataInfo {
                 reproducible: true,
@@ -117,6 +148,265 @@ impl BuildManifest {
         fs::write(path, json)?;
         Ok(())
     }
+
+    /// Records source file hashes so verifiers can confirm the exact sources without a rebuild
+    ///
+    /// Each entry of `paths` (relative to `root`) is either a file, hashed as is, or a
+    /// directory, whose `.rs` files are hashed recursively. Missing paths are skipped and
+    /// `target/` directories are never entered. Entries are sorted by path.
+    pub fn with_source_files(mut self, root: &Path, paths: &[String]) -> Result<Self> {
+        let mut files = Vec::new();
+        for path in paths {
+            let full = root.join(path);
+            if full.is_dir() {
+                collect_rust_files(&full, &mut files)?;
+            } else if full.is_file() {
+                files.push(full);
+            }
+        }
+
+        let mut source_files = files
+            .iter()
+            .map(|file| {
+                let relative = file.strip_prefix(root).unwrap_or(file);
+                let hash = hex::encode(sha256(&fs::read(file)?));
+                Ok((relative.to_string_lossy().replace('\\', "/"), hash))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        source_files.sort();
+        source_files.dedup();
+
+        self.source.source_files = source_files;
+        Ok(self)
+    }
+
+    /// Loads a build-manifest.json and checks that its required fields are usable
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+        let manifest: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Malformed manifest {}", path.display()))?;
+        manifest
+            .validate()
+            .with_context(|| format!("Invalid manifest {}", path.display()))?;
+        Ok(manifest)
+    }
+
+    /// Compact JSON in field declaration order; this, not the pretty file, is what gets signed
+    pub fn canonical_json(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("manifest serializes to JSON")
+    }
+
+    /// Signs the canonical JSON so consumers can check who attested to the build
+    pub fn sign(&self, key: &SigningKey) -> SignedManifest {
+        let signature = key.sign(&self.canonical_json());
+        SignedManifest {
+            public_key: encode_key(key.verifying_key().as_bytes()),
+            signature: encode_key(&signature.to_bytes()),
+        }
+    }
+
+    /// Checks the fields verification relies on: source commit, WASM hash and size
+    pub fn validate(&self) -> Result<()> {
+        if self.source.commit.trim().is_empty() {
+            anyhow::bail!("source.commit is empty");
+        }
+
+        let (algorithm, digest) = self.output.wasm_hash.split_once(':').with_context(|| {
+            format!(
+                "output.wasm_hash '{}' has no algorithm prefix (expected e.g. 'sha256:<hex>')",
+                self.output.wasm_hash
+            )
+        })?;
+        let Some((_, hex_len)) = HASH_ALGORITHMS.iter().find(|(name, _)| *name == algorithm) else {
+            anyhow::bail!(
+                "output.wasm_hash uses unsupported algorithm '{}'",
+                algorithm
+            );
+        };
+        if digest.len() != *hex_len || !digest.bytes().all(|b| b.is_ascii_hexdigit()) {
+            anyhow::bail!(
+                "output.wasm_hash digest must be {} hex characters for {}, got '{}'",
+                hex_len,
+                algorithm,
+                digest
+            );
+        }
+
+        if self.output.wasm_size == 0 {
+            anyhow::bail!("output.wasm_size must be positive");
+        }
+
+        Ok(())
+    }
+
+    /// Cross-checks the manifest against SHA256SUMS without reading the wasm
+    ///
+    /// Returns one message per inconsistency, empty when consistent: `output.filename` must
+    /// have a SHA256SUMS entry matching `output.wasm_hash`, the toolchain versions must be set,
+    /// standards must be listed (including NEP-330), and a recorded `binary_version` must name
+    /// `source.commit`.
+    pub fn consistency_issues(&self, checksums: &SHA256SUMS) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        let filename = &self.output.filename;
+        let digest = self.output.wasm_hash.split_once(':').map_or("", |(_, d)| d);
+        match checksums.hash_for(filename) {
+            None => issues.push(format!(
+                "output.filename '{}' has no entry in SHA256SUMS",
+                filename
+            )),
+            Some(hash) if !hash.eq_ignore_ascii_case(digest) => issues.push(format!(
+                "output.wasm_hash '{}' doesn't match the SHA256SUMS entry for {} ('{}')",
+                self.output.wasm_hash, filename, hash
+            )),
+            Some(_) => {}
+        }
+
+        let toolchain = &self.build.toolchain;
+        for (field, version) in [
+            ("rust_version", &toolchain.rust_version),
+            ("near_sdk_version", &toolchain.near_sdk_version),
+            ("cargo_near_version", &toolchain.cargo_near_version),
+        ] {
+            if version.trim().is_empty() {
+                issues.push(format!("build.toolchain.{} is empty", field));
+            }
+        }
+
+        let standards = &self.metadata.standards;
+        if !standards.iter().any(|standard| standard.name == "nep330") {
+            issues.push("metadata.standards doesn't list nep330".to_string());
+        }
+        for standard in standards {
+            if standard.version.trim().is_empty() {
+                issues.push(format!(
+                    "metadata.standards entry '{}' has no version",
+                    standard.name
+                ));
+            }
+        }
+
+        if let Some(version) = &self.output.binary_version {
+            if !version.starts_with(&format!("{}+state.", self.source.commit)) {
+                issues.push(format!(
+                    "output.binary_version '{}' doesn't match source.commit '{}'",
+                    version, self.source.commit
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+impl SignedManifest {
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read signature {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Malformed signature {}", path.display()))
+    }
+
+    /// Checks that `expected` signed exactly this manifest
+    pub fn verify(&self, manifest: &BuildManifest, expected: &VerifyingKey) -> Result<()> {
+        let public_key = parse_public_key(&self.public_key)?;
+        if public_key != *expected {
+            anyhow::bail!(
+                "Manifest was signed by {}, expected {}",
+                self.public_key,
+                encode_key(expected.as_bytes())
+            );
+        }
+        let bytes: [u8; 64] = decode_key(&self.signature)?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Signature must be 64 bytes"))?;
+        expected
+            .verify(&manifest.canonical_json(), &Signature::from_bytes(&bytes))
+            .context("Manifest signature does not match its contents")
+    }
+}
+
+/// Parses an `ed25519:<base58>` public key
+pub fn parse_public_key(key: &str) -> Result<VerifyingKey> {
+    let bytes: [u8; 32] = decode_key(key)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Public key must be 32 bytes"))?;
+    VerifyingKey::from_bytes(&bytes).context("Invalid ed25519 public key")
+}
+
+/// Loads a signing key from a NEAR credentials file (`private_key`/`secret_key` field) or a
+/// file holding just the `ed25519:<base58>` secret key (64-byte keypair or 32-byte seed)
+pub fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read key file {}", path.display()))?;
+    let content = content.trim();
+    let secret = if content.starts_with('{') {
+        let value: serde_json::Value = serde_json::from_str(content)
+            .with_context(|| format!("Malformed key file {}", path.display()))?;
+        value
+            .get("private_key")
+            .or_else(|| value.get("secret_key"))
+            .and_then(|key| key.as_str())
+            .map(str::to_string)
+            .with_context(|| format!("No private_key in {}", path.display()))?
+    } else {
+        content.to_string()
+    };
+
+    let bytes = decode_key(&secret)?;
+    let seed: [u8; 32] = bytes
+        .get(..32)
+        .filter(|_| bytes.len() == 32 || bytes.len() == 64)
+        .and_then(|seed| seed.try_into().ok())
+        .context("Secret key must be 32 or 64 bytes")?;
+    let key = SigningKey::from_bytes(&seed);
+    if bytes.len() == 64 && bytes[32..] != key.verifying_key().as_bytes()[..] {
+        anyhow::bail!("Secret key's public half does not match its seed");
+    }
+    Ok(key)
+}
+
+fn encode_key(bytes: &[u8]) -> String {
+    format!("ed25519:{}", bs58::encode(bytes).into_string())
+}
+
+fn decode_key(key: &str) -> Result<Vec<u8>> {
+    let data = key
+        .strip_prefix("ed25519:")
+        .with_context(|| format!("Expected 'ed25519:<base58>', got '{}'", key))?;
+    bs58::decode(data)
+        .into_vec()
+        .with_context(|| format!("Invalid base58 in '{}'", key))
+}
+
+/// Version string the contract embeds: `<git commit>+state.<STATE_VERSION>`
+pub fn binary_version(commit: &str, state_version: u32) -> String {
+    format!("{}+state.{}", commit, state_version)
+}
+
+/// Commit of the current checkout ("unknown" outside git), as recorded in the manifest
+pub fn git_commit() -> String {
+    get_git_info().1
+}
+
+/// Finds `const STATE_VERSION: u32 = N;` in the `.rs` files under `src_dir`
+fn find_state_version(src_dir: &Path) -> Option<u32> {
+    let mut files = Vec::new();
+    collect_rust_files(src_dir, &mut files).ok()?;
+    files.sort();
+    files.iter().find_map(|file| {
+        let content = fs::read_to_string(file).ok()?;
+        content.lines().find_map(|line| {
+            let (_, value) = line.split_once("const STATE_VERSION: u32 =")?;
+            value.trim().trim_end_matches(';').trim().parse().ok()
+        })
+    })
 }
 
 fn sha256(data: &[u8]) -> Vec<u8> {
@@ -126,6 +416,20 @@ fn sha256(data: &[u8]) -> Vec<u8> {
     hasher.finalize().to_vec()
 }
 
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if path.file_name().and_then(|s| s.to_str()) != Some("target") {
+                collect_rust_files(&path, files)?;
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
 fn get_git_info() -> (String, String, Option<String>) {
     use std::process::Command;
 
@@ -182,3 +486,173 @@ fn get_near_sdk_version() -> Result<String> {
 
     anyhow::bail!("Could not find near-sdk version in Cargo.toml")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{}-{}.json", name, std::process::id()))
+    }
+
+    fn sample_manifest(name: &str) -> BuildManifest {
+        let wasm = manifest_path(&format!("{}-wasm", name));
+        fs::write(&wasm, b"\0asm").unwrap();
+        let manifest = BuildManifest::generate(&wasm).unwrap();
+        fs::remove_file(&wasm).unwrap();
+        manifest
+    }
+
+    #[test]
+    fn test_from_file_round_trip() {
+        let path = manifest_path("manifest-round-trip");
+        let mut manifest = sample_manifest("manifest-round-trip");
+        manifest.source.commit = "abc123".to_string();
+        manifest.write_to_file(&path).unwrap();
+
+        let loaded = BuildManifest::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.output.wasm_hash, manifest.output.wasm_hash);
+        assert_eq!(loaded.output.wasm_size, 4);
+    }
+
+    #[test]
+    fn test_with_source_files_skips_target() {
+        let root = manifest_path("manifest-sources");
+        fs::create_dir_all(root.join("src/nested")).unwrap();
+        fs::create_dir_all(root.join("src/target")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub fn a() {}").unwrap();
+        fs::write(root.join("src/nested/mod.rs"), "").unwrap();
+        fs::write(root.join("src/notes.txt"), "").unwrap();
+        fs::write(root.join("src/target/gen.rs"), "").unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]").unwrap();
+
+        let paths: Vec<String> = DEFAULT_SOURCE_PATHS.iter().map(|p| p.to_string()).collect();
+        let manifest = sample_manifest("manifest-sources")
+            .with_source_files(&root, &paths)
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let files: Vec<&str> = manifest
+            .source
+            .source_files
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect();
+        assert_eq!(files, vec!["Cargo.toml", "src/lib.rs", "src/nested/mod.rs"]);
+        assert_eq!(
+            manifest.source.source_files[1].1,
+            hex::encode(sha256(b"pub fn a() {}"))
+        );
+    }
+
+    #[test]
+    fn test_find_state_version() {
+        let root = manifest_path("manifest-state-version");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("lib.rs"), "mod validation;").unwrap();
+        fs::write(
+            root.join("validation.rs"),
+            "/// Current state version\npub const STATE_VERSION: u32 = 7;\n",
+        )
+        .unwrap();
+        let found = find_state_version(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(found, Some(7));
+        assert_eq!(binary_version("abc123", 7), "abc123+state.7");
+        assert_eq!(find_state_version(Path::new("does-not-exist")), None);
+    }
+
+    #[test]
+    fn test_consistency_issues() {
+        let mut manifest = sample_manifest("manifest-consistency");
+        manifest.source.commit = "abc123".to_string();
+        manifest.output.binary_version = Some(binary_version("abc123", 2));
+        let digest = manifest.output.wasm_hash["sha256:".len()..].to_string();
+
+        let sums_path = manifest_path("manifest-consistency-sums");
+        let sums = |content: String| {
+            fs::write(&sums_path, content).unwrap();
+            SHA256SUMS::from_file(&sums_path).unwrap()
+        };
+        let matching = sums(format!("{} *{}\n", digest, manifest.output.filename));
+        let other_hash = sums(format!(
+            "{} *{}\n",
+            "0".repeat(64),
+            manifest.output.filename
+        ));
+        let other_file = sums(format!("{} *other.wasm\n", digest));
+        fs::remove_file(&sums_path).unwrap();
+
+        assert!(manifest.consistency_issues(&matching).is_empty());
+        let issues = manifest.consistency_issues(&other_hash);
+        assert!(
+            issues[0].contains("doesn't match the SHA256SUMS entry"),
+            "{:?}",
+            issues
+        );
+        let issues = manifest.consistency_issues(&other_file);
+        assert!(
+            issues[0].contains("has no entry in SHA256SUMS"),
+            "{:?}",
+            issues
+        );
+
+        manifest.metadata.standards.clear();
+        manifest.output.binary_version = Some(binary_version("def456", 2));
+        let issues = manifest.consistency_issues(&matching);
+        assert_eq!(issues.len(), 2, "{:?}", issues);
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_hash() {
+        let mut manifest = sample_manifest("manifest-bad-hash");
+        manifest.source.commit = "abc123".to_string();
+
+        manifest.output.wasm_hash = "md5:abc".to_string();
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("unsupported algorithm 'md5'"), "{}", err);
+
+        manifest.output.wasm_hash = "sha256:xyz".to_string();
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("must be 64 hex characters"), "{}", err);
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let mut manifest = sample_manifest("manifest-sign");
+        let signed = manifest.sign(&key);
+        signed.verify(&manifest, &key.verifying_key()).unwrap();
+
+        // A key file in NEAR credentials form yields the same key
+        let key_path = manifest_path("manifest-sign-key");
+        let mut keypair = key.to_bytes().to_vec();
+        keypair.extend_from_slice(key.verifying_key().as_bytes());
+        let credentials = serde_json::json!({
+            "account_id": "builder.near",
+            "public_key": signed.public_key,
+            "private_key": encode_key(&keypair),
+        });
+        fs::write(&key_path, credentials.to_string()).unwrap();
+        let loaded = load_signing_key(&key_path).unwrap();
+        fs::remove_file(&key_path).unwrap();
+        assert_eq!(loaded.to_bytes(), key.to_bytes());
+        assert_eq!(
+            parse_public_key(&signed.public_key).unwrap(),
+            key.verifying_key()
+        );
+
+        let other = SigningKey::from_bytes(&[8; 32]).verifying_key();
+        let err = signed.verify(&manifest, &other).unwrap_err().to_string();
+        assert!(err.contains("expected"), "{}", err);
+
+        manifest.output.wasm_size += 1;
+        let err = signed
+            .verify(&manifest, &key.verifying_key())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not match"), "{}", err);
+    }
+}
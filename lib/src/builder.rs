@@ -0,0 +1,228 @@
+use near_account_id::AccountId;
+use near_multisig_types::{Action, U128};
+use serde_json::{json, Value};
+
+/// Builds `submit_transaction` arguments for the basic multisig
+///
+/// Actions are serialized through [`near_multisig_types::Action`], so the output has the
+/// contract's exact layout. Note that `FunctionCall` args are a `Vec<u8>` in the contract and
+/// therefore go over JSON as a byte array, not base64.
+///
+/// ```
+/// use near_multisig_lib::builder::TransactionBuilder;
+/// use serde_json::json;
+///
+/// let args = TransactionBuilder::new("bob.near".parse().unwrap())
+///     .transfer(1_000)
+///     .function_call("ping", &json!({}), 5_000_000_000_000, 0)
+///     .expiration_hours(24)
+///     .build();
+/// assert_eq!(args["actions"][1]["FunctionCall"]["args"], json!([123, 125]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    receiver_id: AccountId,
+    actions: Vec<Action>,
+    expiration_hours: Option<u64>,
+}
+
+impl TransactionBuilder {
+    pub fn new(receiver_id: AccountId) -> Self {
+        Self {
+            receiver_id,
+            actions: Vec::new(),
+            expiration_hours: None,
+        }
+    }
+
+    /// Appends a NEAR transfer (yoctoNEAR)
+    pub fn transfer(mut self, amount: u128) -> Self {
+        self.actions.push(Action::Transfer { amount });
+        self
+    }
+
+    /// Appends a function call whose JSON `args` are encoded to bytes for the contract
+    pub fn function_call(
+        self,
+        method_name: impl Into<String>,
+        args: &Value,
+        gas: u64,
+        deposit: u128,
+    ) -> Self {
+        self.function_call_raw(method_name, args.to_string().into_bytes(), gas, deposit)
+    }
+
+    /// Appends a function call with pre-encoded `args` (e.g. borsh for non-JSON contracts)
+    pub fn function_call_raw(
+        mut self,
+        method_name: impl Into<String>,
+        args: Vec<u8>,
+        gas: u64,
+        deposit: u128,
+    ) -> Self {
+        self.actions.push(Action::FunctionCall {
+            method_name: method_name.into(),
+            args,
+            gas,
+            deposit,
+        });
+        self
+    }
+
+    /// Appends a NEP-141 token transfer; the builder's receiver is the token contract
+    pub fn ft_transfer(
+        mut self,
+        receiver_id: AccountId,
+        amount: u128,
+        memo: Option<String>,
+    ) -> Self {
+        self.actions.push(Action::FtTransfer {
+            token_id: self.receiver_id.clone(),
+            receiver_id,
+            amount: U128(amount),
+            memo,
+        });
+        self
+    }
+
+    /// Appends a NEP-171 NFT transfer; the builder's receiver is the NFT contract
+    pub fn nft_transfer(
+        mut self,
+        receiver_id: AccountId,
+        token_id: impl Into<String>,
+        memo: Option<String>,
+    ) -> Self {
+        self.actions.push(Action::NftTransfer {
+            contract_id: self.receiver_id.clone(),
+            receiver_id,
+            token_id: token_id.into(),
+            memo,
+        });
+        self
+    }
+
+    pub fn expiration_hours(mut self, hours: u64) -> Self {
+        self.expiration_hours = Some(hours);
+        self
+    }
+
+    /// Actions built so far, e.g. for [`crate::digest::transaction_digest`]
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// JSON arguments for `submit_transaction`; `options` is left out (`None`) when nothing is set
+    pub fn build(&self) -> Value {
+        let mut args = json!({
+            "receiver_id": self.receiver_id,
+            "actions": self.actions,
+        });
+        if let Some(hours) = self.expiration_hours {
+            args["options"] = json!({ "expiration_hours": hours });
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TransactionBuilder {
+        TransactionBuilder::new("bob.near".parse().unwrap())
+            .transfer(1000)
+            .function_call("ping", &json!({}), 5_000_000_000_000, 1)
+    }
+
+    #[test]
+    fn test_build_matches_contract_json() {
+        let args = sample().expiration_hours(24).build();
+        assert_eq!(
+            args,
+            json!({
+                "receiver_id": "bob.near",
+                "actions": [
+                    { "Transfer": { "amount": 1000 } },
+                    {
+                        "FunctionCall": {
+                            "method_name": "ping",
+                            "args": [123, 125],
+                            "gas": 5_000_000_000_000u64,
+                            "deposit": 1,
+                        }
+                    },
+                ],
+                "options": { "expiration_hours": 24 },
+            })
+        );
+        assert!(sample().build().get("options").is_none());
+    }
+
+    #[test]
+    fn test_ft_transfer_amount_is_a_string() {
+        let builder = TransactionBuilder::new("usdc.near".parse().unwrap()).ft_transfer(
+            "bob.near".parse().unwrap(),
+            5_000_000,
+            None,
+        );
+        let args = builder.build();
+        assert_eq!(
+            args["actions"][0],
+            json!({
+                "FtTransfer": {
+                    "token_id": "usdc.near",
+                    "receiver_id": "bob.near",
+                    "amount": "5000000",
+                    "memo": null,
+                }
+            })
+        );
+        let from_json: Vec<Action> = serde_json::from_value(args["actions"].clone()).unwrap();
+        assert_eq!(from_json, builder.actions());
+    }
+
+    #[test]
+    fn test_nft_transfer_json() {
+        let builder = TransactionBuilder::new("names.near".parse().unwrap()).nft_transfer(
+            "bob.near".parse().unwrap(),
+            "alice.names",
+            Some("gift".to_string()),
+        );
+        assert_eq!(
+            builder.build()["actions"][0],
+            json!({
+                "NftTransfer": {
+                    "contract_id": "names.near",
+                    "receiver_id": "bob.near",
+                    "token_id": "alice.names",
+                    "memo": "gift",
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_actions_round_trip() {
+        let builder = sample();
+        let args = builder.build();
+
+        let from_json: Vec<Action> = serde_json::from_value(args["actions"].clone()).unwrap();
+        assert_eq!(from_json, builder.actions());
+
+        let bytes = borsh::to_vec(builder.actions()).unwrap();
+        let from_borsh: Vec<Action> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(from_borsh, builder.actions());
+
+        // Same actions as the contract's digest test vector
+        let digest = crate::digest::transaction_digest(
+            &"multisig.near".parse().unwrap(),
+            0,
+            &"bob.near".parse().unwrap(),
+            builder.actions(),
+        );
+        assert_eq!(
+            hex::encode(digest),
+            "54cacbe403e58aa637543c36ac88faf680aa9eaaa6adabf2f6f742b3af62f1c6"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -108,7 +109,16 @@ impl SHA256SUMS {
         Ok(results)
     }
 
-    fn hash_file(path: &Path) -> Result<String> {
+    /// Recorded hash of `filename`, if SHA256SUMS lists it
+    pub fn hash_for(&self, filename: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.filename == filename)
+            .map(|entry| entry.hash.as_str())
+    }
+
+    /// Hex-encoded SHA-256 of a file, as written to SHA256SUMS
+    pub fn hash_file(path: &Path) -> Result<String> {
         use sha2::{Digest, Sha256};
         let bytes = fs::read(path)?;
         let hash = Sha256::digest(&bytes);
@@ -116,13 +126,35 @@ impl SHA256SUMS {
     }
 }
 
-#[derive(Default)]
+/// Artifact type of a checksummed file, derived from its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    /// Contract bytecode, the security-critical artifact
+    Wasm,
+    /// JSON metadata such as ABIs and build manifests
+    Metadata,
+    Other,
+}
+
+impl ArtifactKind {
+    pub fn from_filename(filename: &str) -> Self {
+        match Path::new(filename).extension().and_then(|s| s.to_str()) {
+            Some("wasm") => Self::Wasm,
+            Some("json") => Self::Metadata,
+            _ => Self::Other,
+        }
+    }
+}
+
+#[derive(Default, Serialize)]
 pub struct VerificationResult {
     pub verified: Vec<String>,
     pub mismatch: Vec<ChecksumMismatch>,
     pub missing: Vec<String>,
 }
 
+#[derive(Serialize)]
 pub struct ChecksumMismatch {
     pub filename: String,
     pub expected: String,
@@ -133,4 +165,15 @@ impl VerificationResult {
     pub fn is_success(&self) -> bool {
         self.mismatch.is_empty() && self.missing.is_empty()
     }
+
+    /// True when no artifact of the given kind mismatched or went missing
+    pub fn is_success_for(&self, kind: ArtifactKind) -> bool {
+        self.mismatch
+            .iter()
+            .all(|m| ArtifactKind::from_filename(&m.filename) != kind)
+            && self
+                .missing
+                .iter()
+                .all(|f| ArtifactKind::from_filename(f) != kind)
+    }
 }
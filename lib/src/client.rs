@@ -0,0 +1,182 @@
+use crate::rpc::RpcClient;
+use anyhow::{Context, Result};
+use near_account_id::AccountId;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Page size used when counting pending transactions
+const PENDING_PAGE_SIZE: u64 = 100;
+
+/// Off-chain summary of a deployed multisig
+#[derive(Debug, Serialize)]
+pub struct MultisigSummary {
+    pub account_id: AccountId,
+    pub owners: Vec<OwnerInfo>,
+    pub threshold: Option<u32>,
+    pub callback_gas: Option<u64>,
+    pub storage_deposit: Option<u128>,
+    pub state_version: Option<u32>,
+    /// From `get_binary_version`, on deployments that expose it
+    pub binary_version: Option<String>,
+    pub transaction_count: u64,
+    pub pending_count: u64,
+}
+
+/// An owner and, for weighted multisigs, their voting weight
+#[derive(Debug, Serialize)]
+pub struct OwnerInfo {
+    pub account_id: String,
+    pub weight: Option<u32>,
+}
+
+/// Mirrors the contract's `get_config` view (newer deployments only)
+#[derive(Deserialize)]
+struct ContractConfig {
+    owners: Vec<Value>,
+    num_confirmations: u32,
+    callback_gas: u64,
+    storage_deposit: u128,
+    state_version: u32,
+}
+
+/// Read-only client for querying deployed multisig contracts over RPC
+pub struct MultisigClient {
+    rpc: RpcClient,
+}
+
+impl MultisigClient {
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc: RpcClient::new(rpc_url),
+        }
+    }
+
+    /// Creates a client for `mainnet`, `testnet`, or a custom RPC URL
+    pub fn for_network(network: &str) -> Result<Self> {
+        Ok(Self {
+            rpc: RpcClient::for_network(network)?,
+        })
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Summarizes each contract in turn, failing on the first unreachable one
+    pub async fn get_summaries(&self, accounts: &[AccountId]) -> Result<Vec<MultisigSummary>> {
+        let mut summaries = Vec::with_capacity(accounts.len());
+        for account_id in accounts {
+            let summary = self
+                .get_summary(account_id)
+                .await
+                .with_context(|| format!("Failed to query {}", account_id))?;
+            summaries.push(summary);
+        }
+        Ok(summaries)
+    }
+
+    /// Summarizes a single contract, falling back to the older getters when
+    /// `get_config` isn't exposed
+    pub async fn get_summary(&self, account_id: &AccountId) -> Result<MultisigSummary> {
+        let account = account_id.as_str();
+
+        let mut summary = match self
+            .rpc
+            .view::<ContractConfig>(account, "get_config", json!({}))
+            .await?
+        {
+            Some(config) => MultisigSummary {
+                account_id: account_id.clone(),
+                owners: config.owners.iter().map(parse_owner).collect(),
+                threshold: Some(config.num_confirmations),
+                callback_gas: Some(config.callback_gas),
+                storage_deposit: Some(config.storage_deposit),
+                state_version: Some(config.state_version),
+                binary_version: None,
+                transaction_count: 0,
+                pending_count: 0,
+            },
+            None => {
+                let owners: Vec<Value> = self
+                    .rpc
+                    .view(account, "get_owners", json!({}))
+                    .await?
+                    .context("Contract does not expose get_owners - is this a multisig?")?;
+                // Weighted multisigs expose an approval threshold instead of a confirmation count
+                let threshold = match self
+                    .rpc
+                    .view(account, "get_num_confirmations", json!({}))
+                    .await?
+                {
+                    Some(threshold) => Some(threshold),
+                    None => {
+                        self.rpc
+                            .view(account, "get_approval_threshold", json!({}))
+                            .await?
+                    }
+                };
+                MultisigSummary {
+                    account_id: account_id.clone(),
+                    owners: owners.iter().map(parse_owner).collect(),
+                    threshold,
+                    callback_gas: None,
+                    storage_deposit: self
+                        .rpc
+                        .view(account, "get_storage_deposit", json!({}))
+                        .await?,
+                    state_version: None,
+                    binary_version: None,
+                    transaction_count: 0,
+                    pending_count: 0,
+                }
+            }
+        };
+
+        summary.binary_version = self
+            .rpc
+            .view(account, "get_binary_version", json!({}))
+            .await?;
+
+        summary.transaction_count = self
+            .rpc
+            .view(account, "get_transaction_count", json!({}))
+            .await?
+            .context("Contract does not expose get_transaction_count")?;
+
+        // Page through the pending queue; storage is capped so this stays bounded
+        let mut from_index = 0u64;
+        while from_index < summary.transaction_count {
+            let page: Vec<Value> = self
+                .rpc
+                .view(
+                    account,
+                    "get_pending_transactions_paginated",
+                    json!({ "from_index": from_index, "limit": PENDING_PAGE_SIZE }),
+                )
+                .await?
+                .unwrap_or_default();
+            summary.pending_count += page.len() as u64;
+            from_index += PENDING_PAGE_SIZE;
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Owners are plain account IDs, or `[account, weight]` pairs for weighted multisigs
+fn parse_owner(owner: &Value) -> OwnerInfo {
+    match owner {
+        Value::Array(pair) if pair.len() == 2 => OwnerInfo {
+            account_id: pair[0].as_str().unwrap_or_default().to_string(),
+            weight: pair[1].as_u64().map(|w| w as u32),
+        },
+        Value::String(account) => OwnerInfo {
+            account_id: account.clone(),
+            weight: None,
+        },
+        other => OwnerInfo {
+            account_id: other.to_string(),
+            weight: None,
+        },
+    }
+}
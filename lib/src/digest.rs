@@ -0,0 +1,48 @@
+use near_account_id::AccountId;
+use near_multisig_types::Action;
+use sha2::{Digest, Sha256};
+
+/// Canonical digest of a basic-multisig transaction
+///
+/// sha256 of borsh `(contract_id, tx_id, receiver_id, actions)`, byte-for-byte the same as the
+/// contract's `get_transaction_digest` view. Owners sign it for `confirm_with_signatures`, or
+/// with the next transaction ID for `submit_with_confirmations`.
+pub fn transaction_digest(
+    contract_id: &AccountId,
+    tx_id: u64,
+    receiver_id: &AccountId,
+    actions: &[Action],
+) -> [u8; 32] {
+    let payload = borsh::to_vec(&(contract_id, tx_id, receiver_id, actions))
+        .expect("borsh serialization into a Vec can't fail");
+    Sha256::digest(payload).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_contract_view() {
+        // Same vector as the basic contract's `test_transaction_digest_matches_lib`
+        let actions = vec![
+            Action::Transfer { amount: 1000 },
+            Action::FunctionCall {
+                method_name: "ping".to_string(),
+                args: b"{}".to_vec(),
+                gas: 5_000_000_000_000,
+                deposit: 1,
+            },
+        ];
+        let digest = transaction_digest(
+            &"multisig.near".parse().unwrap(),
+            0,
+            &"bob.near".parse().unwrap(),
+            &actions,
+        );
+        assert_eq!(
+            hex::encode(digest),
+            "54cacbe403e58aa637543c36ac88faf680aa9eaaa6adabf2f6f742b3af62f1c6"
+        );
+    }
+}
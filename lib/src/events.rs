@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Prefix NEP-297 events are logged with
+pub const EVENT_LOG_PREFIX: &str = "EVENT_JSON:";
+
+/// A decoded NEP-297 event log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLog {
+    pub standard: String,
+    pub version: String,
+    pub event: String,
+    #[serde(default)]
+    pub data: Value,
+}
+
+impl EventLog {
+    /// Decodes an `EVENT_JSON:` log line, returning `None` for plain logs
+    pub fn parse(log: &str) -> Option<Self> {
+        let json = log.strip_prefix(EVENT_LOG_PREFIX)?;
+        serde_json::from_str(json.trim()).ok()
+    }
+
+    /// Whether this event matches a name given as `TransactionReady` or `transaction_ready`
+    pub fn is(&self, name: &str) -> bool {
+        self.event == to_snake_case(name)
+    }
+}
+
+/// Converts an event variant name (`TransactionReady`) to its logged form (`transaction_ready`)
+pub fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
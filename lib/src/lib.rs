@@ -1,2 +1,7 @@
 pub mod build_manifest;
+pub mod builder;
 pub mod checksum;
+pub mod client;
+pub mod digest;
+pub mod events;
+pub mod rpc;
@@ -0,0 +1,111 @@
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+/// Minimal NEAR JSON-RPC client for `call_function` view queries
+pub struct RpcClient {
+    url: String,
+    http: reqwest::Client,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Creates a client for `mainnet`, `testnet`, or a custom RPC URL
+    pub fn for_network(network: &str) -> Result<Self> {
+        let url = match network {
+            "mainnet" => "https://rpc.mainnet.near.org",
+            "testnet" => "https://rpc.testnet.near.org",
+            url if url.starts_with("http://") || url.starts_with("https://") => url,
+            _ => anyhow::bail!(
+                "Unknown network: {}. Use mainnet, testnet, or an RPC URL",
+                network
+            ),
+        };
+        Ok(Self::new(url))
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Sends a raw JSON-RPC request and returns the full response body
+    pub async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": "near-multisig",
+            "method": method,
+            "params": params,
+        });
+
+        self.http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach RPC endpoint {}", self.url))?
+            .error_for_status()?
+            .json()
+            .await
+            .context("RPC endpoint returned invalid JSON")
+    }
+
+    /// Sends a JSON-RPC request and returns its `result`, failing on any RPC error
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let mut response = self.request(method, params).await?;
+        if let Some(error) = response.get("error") {
+            anyhow::bail!("RPC error calling {}: {}", method, error);
+        }
+        response
+            .get_mut("result")
+            .map(Value::take)
+            .with_context(|| format!("Malformed RPC response for {}", method))
+    }
+
+    /// Calls a view method, returning `None` if the contract doesn't expose it
+    pub async fn view<T: DeserializeOwned>(
+        &self,
+        account_id: &str,
+        method: &str,
+        args: Value,
+    ) -> Result<Option<T>> {
+        let params = json!({
+            "request_type": "call_function",
+            "finality": "final",
+            "account_id": account_id,
+            "method_name": method,
+            "args_base64": BASE64.encode(args.to_string()),
+        });
+        let response = self.request("query", params).await?;
+
+        // Errors are reported either top-level or inside the result, depending on node version
+        let error = response
+            .get("error")
+            .or_else(|| response.get("result").and_then(|r| r.get("error")));
+        if let Some(error) = error {
+            let message = error.to_string();
+            if message.contains("MethodNotFound") || message.contains("MethodResolveError") {
+                return Ok(None);
+            }
+            anyhow::bail!("RPC error calling {}: {}", method, message);
+        }
+
+        let bytes: Vec<u8> = response
+            .get("result")
+            .and_then(|r| r.get("result"))
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()?
+            .with_context(|| format!("Malformed RPC response for {}", method))?;
+
+        let value = serde_json::from_slice(&bytes)
+            .with_context(|| format!("Unexpected return value from {}", method))?;
+        Ok(Some(value))
+    }
+}
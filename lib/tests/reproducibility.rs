@@ -0,0 +1,111 @@
+//! Reproducibility smoke test: the same project built twice must produce identical WASM
+//!
+//! Generates a project with `near-multisig init`, clones it to a second location and runs
+//! `cargo near build reproducible-wasm` in both. Building from different absolute paths
+//! catches timestamps or paths leaking into the output. Needs Docker and cargo-near:
+//!
+//! ```bash
+//! cargo test -p near-multisig-lib --test reproducibility -- --ignored
+//! ```
+
+use near_multisig_lib::checksum::SHA256SUMS;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const PROJECT_NAME: &str = "repro-smoke";
+
+fn run(dir: &Path, program: &str, args: &[&str]) {
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .unwrap_or_else(|e| panic!("Failed to run {} {:?}: {}", program, args, e));
+    assert!(
+        status.success(),
+        "{} {:?} failed in {}",
+        program,
+        args,
+        dir.display()
+    );
+}
+
+/// Builds the project and returns the hash of the produced WASM
+fn build_and_hash(project: &Path) -> String {
+    run(project, "cargo", &["near", "build", "reproducible-wasm"]);
+
+    let wasm = fs::read_dir(project.join("target/near"))
+        .expect("No target/near directory after build")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| path.extension().and_then(|s| s.to_str()) == Some("wasm"))
+        .expect("No WASM file found in target/near/");
+    SHA256SUMS::hash_file(&wasm).expect("Failed to hash WASM")
+}
+
+#[test]
+#[ignore = "needs Docker and cargo-near; run with --ignored"]
+fn test_reproducible_build_is_deterministic() {
+    let root: PathBuf =
+        std::env::temp_dir().join(format!("near-multisig-repro-{}", std::process::id()));
+    let first = root.join("first");
+    let second = root.join("second");
+    fs::create_dir_all(&first).unwrap();
+
+    // Generate the project with the CLI's init logic
+    let workspace_manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("../Cargo.toml");
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    run(
+        &first,
+        &cargo,
+        &[
+            "run",
+            "--quiet",
+            "--manifest-path",
+            workspace_manifest.to_str().unwrap(),
+            "-p",
+            "near-multisig",
+            "--",
+            "init",
+            PROJECT_NAME,
+        ],
+    );
+
+    // Reproducible builds are --locked and only accept committed sources
+    let project = first.join(PROJECT_NAME);
+    run(&project, &cargo, &["generate-lockfile"]);
+    run(&project, "git", &["init", "--quiet"]);
+    run(&project, "git", &["add", "-A"]);
+    run(
+        &project,
+        "git",
+        &[
+            "-c",
+            "user.name=repro",
+            "-c",
+            "user.email=repro@localhost",
+            "commit",
+            "--quiet",
+            "-m",
+            "init",
+        ],
+    );
+    run(
+        &root,
+        "git",
+        &[
+            "clone",
+            "--quiet",
+            project.to_str().unwrap(),
+            second.to_str().unwrap(),
+        ],
+    );
+
+    let first_hash = build_and_hash(&project);
+    let second_hash = build_and_hash(&second);
+    fs::remove_dir_all(&root).ok();
+
+    assert_eq!(
+        first_hash, second_hash,
+        "Reproducible builds produced different WASM"
+    );
+}
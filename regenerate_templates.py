@@ -2,7 +2,8 @@
 import re
 
 # Read template files
-with open('templates/basic/contract/src/lib.rs') as f:
+# The CLI's basic template is the single-file contract, not the full templates/basic/contract
+with open('templates/basic/standalone/src/lib.rs') as f:
     basic_lib = f.read()
 
 with open('templates/timelock/contract/src/lib.rs') as f:
@@ -11,6 +12,9 @@ with open('templates/timelock/contract/src/lib.rs') as f:
 with open('templates/weighted/contract/src/lib.rs') as f:
     weighted_lib = f.read()
 
+with open('templates/dao/contract/src/lib.rs') as f:
+    dao_lib = f.read()
+
 # Read CLI file
 with open('cli/src/commands/init.rs') as f:
     cli_content = f.read()
@@ -37,6 +41,13 @@ cli_content = re.sub(
     flags=re.DOTALL
 )
 
+cli_content = re.sub(
+    r'const DAO_TEMPLATE_LIB: &str = r#".*?"#;',
+    f'const DAO_TEMPLATE_LIB: &str = r#"{dao_lib}"#;',
+    cli_content,
+    flags=re.DOTALL
+)
+
 # Write back
 with open('cli/src/commands/init.rs', 'w') as f:
     f.write(cli_content)
@@ -45,3 +56,4 @@ print("✅ CLI templates regenerated successfully!")
 print(f"  Basic template: {len(basic_lib)} bytes")
 print(f"  Timelock template: {len(timelock_lib)} bytes")
 print(f"  Weighted template: {len(weighted_lib)} bytes")
+print(f"  DAO template: {len(dao_lib)} bytes")
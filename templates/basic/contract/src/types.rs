@@ -1,86 +1,268 @@
+// Transaction, Action, TxLifecycle, ExpirationPolicy and MultisigEvent live in the
+// near-multisig-types crate (types/), shared with integration tests and tooling.
+pub use near_multisig_types::{
+    Action, ConfirmationEntry, ExpirationPolicy, MultisigEvent, Transaction, TxLifecycle,
+    EVENT_STANDARD,
+};
+use near_sdk::json_types::Base64VecU8;
 use near_sdk::{near, AccountId};
 
-/// Transaction submitted for multisig approval
-#[near(serializers = [json, borsh])]
+/// 64-byte ed25519 signature (base64 in JSON)
+pub type Signature = Base64VecU8;
+
+/// `Transaction` layout stored by version 1 and 2 state, read only by the migrations
+///
+/// Frozen: it must match what deployed v1 and v2 contracts hold, so never add fields here.
+#[near(serializers = [borsh])]
 #[derive(Clone)]
-pub struct Transaction {
+pub struct LegacyTransaction {
     pub id: u64,
     pub receiver_id: AccountId,
     pub actions: Vec<Action>,
     pub confirmations: Vec<AccountId>,
     pub executed: bool,
     pub cancelled: bool,
-    pub storage_depositor: AccountId, // Who paid storage deposit (gets refund)
-    pub expiration: Option<u64>,      // Optional expiration timestamp (nanoseconds)
+    pub storage_depositor: AccountId,
+    pub expiration: Option<u64>,
 }
 
-/// Actions that can be performed in a transaction
-#[near(serializers = [json, borsh])]
-#[derive(Clone)]
-pub enum Action {
-    Transfer {
-        amount: u128,
-    },
-    FunctionCall {
-        method_name: String,
-        args: Vec<u8>,
+impl LegacyTransaction {
+    /// Converts to the current layout, mapping each confirmer to its bitmap bit
+    pub fn into_transaction(self, owner_bit: impl Fn(&AccountId) -> Option<u64>) -> Transaction {
+        Transaction {
+            id: self.id,
+            receiver_id: self.receiver_id,
+            actions: self.actions,
+            confirmations: self.confirmations.iter().filter_map(owner_bit).fold(0, |m, b| m | b),
+            executed: self.executed,
+            cancelled: self.cancelled,
+            storage_depositor: self.storage_depositor,
+            expiration: self.expiration,
+            funded_amount: 0,
+            execute_after: None,
+            retry_count: 0,
+            last_execution_failed: false,
+            finalized_at: None,
+            executor_reward: 0,
+            executor: None,
+            category: None,
+            cancel_votes: Vec::new(),
+            relayer: None,
+            in_progress: false,
+            deposit_exempt: false,
+            action_results: Vec::new(),
+            require_receiver_ack: false,
+            depends_on: None,
+            // Not recorded before v3; `max_proposal_lifetime` counts from the migration instead
+            submitted_at: near_sdk::env::block_timestamp(),
+            revoked_confirmations: 0,
+            // Older versions released the reservation when they finalized a transaction
+            reservation_released: self.executed || self.cancelled,
+        }
+    }
+}
+
+/// Optional settings for `submit_transaction`; omitted fields take their defaults
+#[near(serializers = [json])]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct SubmitOptions {
+    /// Expiration time in hours (None = the contract's `default_expiration_hours`, which
+    /// itself defaults to never expiring)
+    pub expiration_hours: Option<u64>,
+    /// If true, the attachment beyond the storage fee pays for this transaction's value
+    /// instead of the contract balance
+    pub funded_by_deposit: Option<bool>,
+    /// Absolute timestamp (nanoseconds) before which the transaction can't be executed, even
+    /// if fully confirmed. Must be before `expiration` when both are set.
+    pub execute_after: Option<u64>,
+    /// Reward (funded from the attachment, up to `max_executor_reward`) paid to whoever
+    /// successfully executes the transaction. Rewarded transactions can be executed by anyone,
+    /// not just owners.
+    pub executor_reward: Option<u128>,
+    /// Reporting tag (e.g. "payroll"); must be in the category allow-list when one is
+    /// configured
+    pub category: Option<String>,
+    /// If true, execution first offers the transaction to the receiver (see
+    /// `MultisigOfferReceiver`) and only runs the actions once it accepts
+    pub require_receiver_ack: Option<bool>,
+    /// ID of an earlier, not cancelled transaction that must have executed before this one
+    /// can (see `get_dependency_status`)
+    pub depends_on: Option<u64>,
+}
+
+/// One promise action `execute_transaction` would dispatch, from `preview_execution`
+#[near(serializers = [json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionPreview {
+    /// "Transfer" or "FunctionCall"; token transfers preview as their `ft_transfer` /
+    /// `nft_transfer` call
+    pub kind: String,
+    pub receiver_id: AccountId,
+    /// NEAR attached (yoctoNEAR)
+    pub deposit: u128,
+    /// `deposit` in NEAR, e.g. "1.5"
+    pub deposit_near: String,
+    pub method_name: Option<String>,
+    /// Call args as text when they're valid UTF-8 (usually JSON)
+    pub args: Option<String>,
+    pub gas: Option<u64>,
+}
+
+impl ActionPreview {
+    pub fn transfer(receiver_id: AccountId, amount: u128) -> Self {
+        Self {
+            kind: "Transfer".to_string(),
+            receiver_id,
+            deposit: amount,
+            deposit_near: format_near(amount),
+            method_name: None,
+            args: None,
+            gas: None,
+        }
+    }
+
+    pub fn function_call(
+        receiver_id: AccountId,
+        method_name: &str,
+        args: &[u8],
         gas: u64,
         deposit: u128,
-    },
+    ) -> Self {
+        Self {
+            kind: "FunctionCall".to_string(),
+            receiver_id,
+            deposit,
+            deposit_near: format_near(deposit),
+            method_name: Some(method_name.to_string()),
+            args: String::from_utf8(args.to_vec()).ok(),
+            gas: Some(gas),
+        }
+    }
 }
 
-/// Events emitted for off-chain indexing
-#[near(event_json(standard = "multisig"))]
-pub enum MultisigEvent {
-    #[event_version("1.0.0")]
-    TransactionSubmitted {
-        tx_id: u64,
-        submitter: AccountId,
-        receiver_id: AccountId,
-    },
-
-    #[event_version("1.0.0")]
-    TransactionConfirmed {
-        tx_id: u64,
-        confirmer: AccountId,
-        confirmations: u32,
-    },
-
-    #[event_version("1.0.0")]
-    TransactionExecuted { tx_id: u64, success: bool },
-
-    #[event_version("1.0.0")]
-    TransactionCancelled { tx_id: u64, canceller: AccountId },
-
-    #[event_version("1.0.0")]
-    ConfirmationRevoked {
-        tx_id: u64,
-        revoker: AccountId,
-        confirmations: u32,
-    },
-
-    #[event_version("1.0.0")]
-    CallbackGasChanged {
-        old_gas: u64,
-        new_gas: u64,
-        changer: AccountId,
-    },
-
-    #[event_version("1.0.0")]
-    StorageDepositChanged {
-        old_deposit: u128,
-        new_deposit: u128,
-        changer: AccountId,
-    },
-
-    #[event_version("1.0.0")]
-    TransactionReady { tx_id: u64, confirmations: u32 },
-
-    #[event_version("1.0.0")]
-    TransactionsCleanedUp {
-        count: u64,
-        from_index: u64,
-        to_index: u64,
-        cleaner: AccountId,
-    },
+/// Formats yoctoNEAR as NEAR without trailing zeros (1.5, 0.000000000000000000000001, 3)
+fn format_near(yocto: u128) -> String {
+    const YOCTO_PER_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+    let whole = yocto / YOCTO_PER_NEAR;
+    let fraction = yocto % YOCTO_PER_NEAR;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let digits = format!("{:024}", fraction);
+    format!("{}.{}", whole, digits.trim_end_matches('0'))
+}
+
+/// A transaction's dependency, returned by `get_dependency_status`
+#[near(serializers = [json])]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyStatus {
+    pub depends_on: u64,
+    /// None once the dependency has been cleaned up
+    pub state: Option<TxLifecycle>,
+    /// The dependency has executed, so it no longer blocks execution
+    pub satisfied: bool,
+}
+
+/// Snapshot of the multisig configuration returned by `get_config`
+#[near(serializers = [json])]
+pub struct MultisigConfig {
+    pub owners: Vec<AccountId>,
+    pub num_confirmations: u32,
+    pub callback_gas: u64,
+    pub refund_callback_gas: u64,
+    pub storage_deposit: u128,
+    pub max_reserved_bps: u32,
+    pub min_function_call_gas: u64,
+    pub cleanup_grace_period: u64,
+    pub default_expiration_hours: Option<u64>,
+    pub expiration_policy: ExpirationPolicy,
+    pub max_executor_reward: u128,
+    pub mandatory_signer: Option<AccountId>,
+    pub allow_threshold_cancel: bool,
+    pub auto_cleanup: bool,
+    pub max_transaction_value: Option<u128>,
+    pub state_version: u32,
+    pub max_concurrent_executions: u32,
+    pub max_deposit_per_action: Option<u128>,
+    pub max_proposal_lifetime: u64,
+}
+
+/// Validation limits returned by `get_limits`, for client-side pre-validation
+#[near(serializers = [json])]
+pub struct Limits {
+    pub max_actions: u32,
+    pub max_args_len: u32,
+    pub max_method_name_len: u32,
+    pub max_gas_per_action: u64,
+    pub max_total_gas: u64,
+    pub min_function_call_gas: u64,
+    pub max_owners: u32,
+    pub max_transactions: u32,
+    pub max_transaction_value: Option<u128>,
+    pub max_categories: u32,
+    pub max_category_len: u32,
+    pub max_executor_reward: u128,
+    pub max_deposit_per_action: Option<u128>,
+}
+
+/// Reservation cap and current utilization returned by `get_reservation_status`
+#[near(serializers = [json])]
+pub struct ReservationStatus {
+    pub max_reserved_bps: u32,
+    pub reserved_cap: u128,
+    pub reserved_balance: u128,
+    pub utilization_bps: u32,
+}
+
+/// In-flight executions and their cap returned by `get_execution_concurrency`
+#[near(serializers = [json])]
+pub struct ExecutionConcurrency {
+    /// Executions awaiting their callback (`pending_callbacks`)
+    pub in_flight: u32,
+    pub max_concurrent_executions: u32,
+}
+
+/// Whether a transaction can execute now, returned by `get_execution_readiness`
+#[near(serializers = [json])]
+pub struct ExecutionReadiness {
+    /// Confirmations from current owners, the count checked against `required`
+    pub effective_confirmations: u32,
+    /// `num_confirmations` at the time of the call
+    pub required: u32,
+    /// Recorded confirmations no current owner holds (slots, so they can't be named)
+    pub stale_confirmers: u32,
+    /// Past its `expiration` or older than `max_proposal_lifetime`
+    pub is_expired: bool,
+    /// `execute_transaction` would dispatch it now
+    pub is_ready: bool,
+    /// The error `execute_transaction` would fail with, when not ready
+    pub blocking_reason: Option<String>,
+}
+
+/// Breakdown of the contract's NEAR returned by `get_balance_summary`
+#[near(serializers = [json])]
+pub struct BalanceSummary {
+    pub account_balance: u128,
+    pub reserved_balance: u128,
+    pub storage_deposit_held: u128,
+    pub spendable: u128,
+}
+
+/// Governance action recorded in the on-chain audit log
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct AuditEntry {
+    pub timestamp: u64,
+    pub actor: AccountId,
+    pub action_type: String,
+    pub detail: String,
+}
+
+/// Aggregate counters of executed actions returned by `get_usage_stats`
+#[near(serializers = [json])]
+pub struct UsageStats {
+    pub total_transfers_executed: u64,
+    pub total_function_calls_executed: u64,
+    pub total_near_transferred: u128,
 }
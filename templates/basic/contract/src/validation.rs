@@ -1,5 +1,5 @@
 use crate::types::Action;
-use near_sdk::require;
+use near_sdk::{require, AccountId};
 
 // ==================== Security Limits ====================
 // These constants protect the contract from abuse and ensure reliable operation
@@ -8,6 +8,21 @@ use near_sdk::require;
 /// Prevents gas exhaustion from overly complex transactions
 pub const MAX_ACTIONS: usize = 10;
 
+/// Gas attached to the `ft_transfer` call an `FtTransfer` action lowers to (10 TGas)
+pub const FT_TRANSFER_GAS: u64 = 10_000_000_000_000;
+
+/// Deposit NEP-141 requires on `ft_transfer` (exactly 1 yoctoNEAR)
+pub const FT_TRANSFER_DEPOSIT: u128 = 1;
+
+/// Maximum length of an `FtTransfer` or `NftTransfer` memo
+pub const MAX_FT_MEMO_LEN: usize = 256;
+
+/// Gas attached to the `nft_transfer` call an `NftTransfer` action lowers to (20 TGas)
+pub const NFT_TRANSFER_GAS: u64 = 20_000_000_000_000;
+
+/// Deposit NEP-171 requires on `nft_transfer` (exactly 1 yoctoNEAR)
+pub const NFT_TRANSFER_DEPOSIT: u128 = 1;
+
 /// Maximum size of function call arguments (32KB)
 /// Prevents storage bloat and excessive data processing costs
 pub const MAX_ARGS_LEN: usize = 32768;
@@ -25,13 +40,39 @@ pub const MAX_GAS_PER_ACTION: u64 = 100_000_000_000_000;
 pub const MAX_TOTAL_GAS: u64 = 250_000_000_000_000;
 
 /// Maximum number of multisig owners
-/// Prevents iteration costs from becoming too expensive
+/// Prevents iteration costs from becoming too expensive; must stay within the 64 bits of the
+/// `Transaction.confirmations` bitmap
 pub const MAX_OWNERS: usize = 50;
 
 /// Maximum transactions to clean up in a single call
 /// Prevents cleanup operations from running out of gas
 pub const MAX_CLEANUP_BATCH: u32 = 100;
 
+/// Maximum finalized transactions a submission removes when `auto_cleanup` finds the queue full
+pub const AUTO_CLEANUP_BATCH: u64 = 10;
+
+/// Longest a session key may stay valid (30 days, in nanoseconds)
+pub const MAX_SESSION_KEY_LIFETIME: u64 = 30 * 24 * 3_600_000_000_000;
+
+/// Maximum unexpired session keys one owner may hold at once
+/// Bounds the work `remove_owner` does purging them
+pub const MAX_SESSION_KEYS_PER_OWNER: usize = 10;
+
+/// Gas for the receiver's `on_multisig_offer` acknowledgement call (10 TGas)
+pub const RECEIVER_ACK_GAS: u64 = 10_000_000_000_000;
+
+/// Gas the acknowledgement callback needs on top of the actions it dispatches (10 TGas)
+pub const RECEIVER_ACK_CALLBACK_GAS: u64 = 10_000_000_000_000;
+
+/// Maximum transactions `revoke_confirmations` handles in a single call
+pub const MAX_REVOKE_BATCH: usize = 50;
+
+/// Maximum transactions `confirm_transactions` handles in a single call
+pub const MAX_CONFIRM_BATCH: usize = 50;
+
+/// Minimum time between `emit_daily_digest` calls (23 hours, in nanoseconds)
+pub const MIN_DIGEST_INTERVAL: u64 = 23 * 3_600_000_000_000;
+
 /// Storage deposit required per transaction (0.01 NEAR)
 /// Covers storage costs and prevents spam. Refunded when transaction completes.
 pub const TRANSACTION_STORAGE_DEPOSIT: u128 = 10_000_000_000_000_000_000_000; // 0.01 NEAR
@@ -40,6 +81,31 @@ pub const TRANSACTION_STORAGE_DEPOSIT: u128 = 10_000_000_000_000_000_000_000; //
 /// Can be adjusted by owners based on transaction complexity
 pub const DEFAULT_CALLBACK_GAS: u64 = 20_000_000_000_000;
 
+/// Bounds `set_callback_gas` accepts (5-100 TGas)
+pub const MIN_CALLBACK_GAS: u64 = 5_000_000_000_000;
+pub const MAX_CALLBACK_GAS: u64 = 100_000_000_000_000;
+
+/// Fixed cost of the execution callback's bookkeeping (10 TGas)
+pub const CALLBACK_BASE_GAS: u64 = 10_000_000_000_000;
+
+/// Callback gas per action for recording its result (1 TGas)
+pub const CALLBACK_GAS_PER_ACTION: u64 = 1_000_000_000_000;
+
+/// Extra callback gas per cross-contract call, whose failures are logged (2 TGas)
+pub const CALLBACK_GAS_PER_CALL: u64 = 2_000_000_000_000;
+
+/// Callback gas for creating the executor reward transfer (5 TGas)
+pub const CALLBACK_REWARD_GAS: u64 = 5_000_000_000_000;
+
+/// Default gas for storage deposit refund callbacks (5 TGas)
+pub const DEFAULT_REFUND_CALLBACK_GAS: u64 = 5_000_000_000_000;
+
+/// Gas `execute_transaction` itself burns on checks, state writes and promise creation (20 TGas)
+pub const EXECUTE_OVERHEAD_GAS: u64 = 20_000_000_000_000;
+
+/// Protocol limit on the gas attached to a single call (300 TGas)
+pub const MAX_PREPAID_GAS: u64 = 300_000_000_000_000;
+
 /// Minimum balance the contract must maintain (0.1 NEAR)
 /// Ensures the contract can't be drained and remains operational
 pub const MIN_CONTRACT_BALANCE: u128 = 100_000_000_000_000_000_000_000; // 0.1 NEAR
@@ -48,20 +114,154 @@ pub const MIN_CONTRACT_BALANCE: u128 = 100_000_000_000_000_000_000_000; // 0.1 N
 /// Prevents unbounded storage growth that could make the contract unusable
 pub const MAX_TRANSACTIONS: u32 = 1000;
 
+/// Default minimum gas per function call action (2 TGas)
+/// Calls below this deterministically fail on execution, so they're rejected at submission
+pub const DEFAULT_MIN_FUNCTION_CALL_GAS: u64 = 2_000_000_000_000;
+
+/// Methods a self-targeted transaction may call (all `#[private]`)
+/// Everything else on the contract, like callbacks and migration, must never be self-invoked
+pub const GOVERNANCE_METHODS: [&str; 29] = [
+    "set_callback_gas",
+    "set_refund_callback_gas",
+    "set_storage_deposit",
+    "set_max_reserved_bps",
+    "set_min_function_call_gas",
+    "set_cleanup_grace_period",
+    "set_default_expiration_hours",
+    "set_expiration_policy",
+    "set_max_executor_reward",
+    "set_mandatory_signer",
+    "set_allow_threshold_cancel",
+    "set_auto_cleanup",
+    "set_max_transaction_value",
+    "prune_audit_log",
+    "force_reset_pending_callbacks",
+    "recompute_reserved_balance",
+    "release_transaction_reservation",
+    "set_max_concurrent_executions",
+    "rotate_access_key",
+    "set_max_deposit_per_action",
+    "set_max_proposal_lifetime",
+    "add_deposit_exempt",
+    "remove_deposit_exempt",
+    "add_allowed_method",
+    "remove_allowed_method",
+    "add_category",
+    "remove_category",
+    "add_relayer",
+    "remove_relayer",
+];
+
+/// `#[private]` methods outside `GOVERNANCE_METHODS`
+/// A function-call key on the contract account calls as the contract itself, so it must never be
+/// scoped to any of these or to a governance method
+pub const INTERNAL_METHODS: [&str; 8] = [
+    "migrate",
+    "migrate_init",
+    "migrate_from_v2",
+    "migrate_init_from_v2",
+    "import_transactions",
+    "on_execute_callback",
+    "on_receiver_ack",
+    "on_refund_callback",
+];
+
+/// Domain tag prefixed to the message owners sign for `submit_transaction_relayed`
+/// Keeps those signatures from verifying as transaction digests, and vice versa
+pub const RELAYED_SUBMISSION_DOMAIN: &str = "near-multisig:relayed-submission";
+
+/// Gas for the self-call that rotates a function-call access key (10 TGas)
+pub const ROTATE_KEY_GAS: u64 = 10_000_000_000_000;
+
+/// Basis-point denominator for percentage limits (10000 bps = 100%)
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Default cap on total reserved balance (10000 bps = 100% of contract balance)
+/// Owners can lower this to keep a share of funds always available
+pub const DEFAULT_MAX_RESERVED_BPS: u32 = 10_000;
+
+/// Default cap on executions awaiting their callback; at most MAX_TRANSACTIONS can be in
+/// flight anyway, so this preserves the uncapped behavior
+pub const DEFAULT_MAX_CONCURRENT_EXECUTIONS: u32 = MAX_TRANSACTIONS;
+
+/// Storage staking price on NEAR (10^19 yoctoNEAR per byte, i.e. 1 NEAR per 100KB)
+pub const STORAGE_BYTE_COST: u128 = 10_000_000_000_000_000_000;
+
+/// Protocol overhead charged per storage record, on top of key and value bytes
+pub const STORAGE_RECORD_OVERHEAD: u64 = 40;
+
+/// Account ID length assumed for storage estimates (named accounts are usually much
+/// shorter than the 64-byte implicit ones)
+pub const ESTIMATE_ACCOUNT_ID_LEN: u64 = 32;
+
+/// Function call args size assumed for storage estimates
+pub const ESTIMATE_ARGS_LEN: u64 = 128;
+
+/// Default cap on per-transaction executor rewards (0.1 NEAR)
+pub const DEFAULT_MAX_EXECUTOR_REWARD: u128 = 100_000_000_000_000_000_000_000;
+
+/// Highest executor reward cap owners can configure (10 NEAR)
+pub const MAX_EXECUTOR_REWARD_CAP: u128 = 10_000_000_000_000_000_000_000_000;
+
+/// Maximum length of a transaction category tag
+pub const MAX_CATEGORY_LEN: usize = 32;
+
+/// Maximum number of categories in the allow-list
+pub const MAX_CATEGORIES: u32 = 50;
+
+/// Maximum number of methods in one receiver's method allow-list
+pub const MAX_ALLOWED_METHODS: u32 = 50;
+
+/// Maximum number of audit log entries kept on-chain
+/// Bounds storage; once reached, each new entry evicts the oldest one
+pub const MAX_AUDIT_ENTRIES: u32 = 1000;
+
+/// Default age after which a pending proposal counts as expired, even without an
+/// `expiration` (365 days in nanoseconds)
+pub const DEFAULT_MAX_PROPOSAL_LIFETIME: u64 = 365 * 24 * 3_600_000_000_000;
+
+/// Shortest allowed `max_proposal_lifetime` (1 hour in nanoseconds)
+pub const MIN_PROPOSAL_LIFETIME: u64 = 3_600_000_000_000;
+
+/// Maximum transactions per `purge_expired_transactions` call
+pub const MAX_PURGE_BATCH: usize = 50;
+
+/// Longest allowed cleanup grace period (30 days in nanoseconds)
+pub const MAX_CLEANUP_GRACE_PERIOD: u64 = 30 * 24 * 3_600_000_000_000;
+
+/// Maximum number of times a failed execution can be retried with `retry_transaction`
+/// Stops a transaction that can never succeed from being re-run indefinitely
+pub const MAX_EXECUTION_RETRIES: u32 = 3;
+
 /// Current state version for migration tracking
 /// Incremented when storage structure changes require migration
-pub const STATE_VERSION: u32 = 2;
+pub const STATE_VERSION: u32 = 3;
+
+/// Commit this wasm was built from, embedded at compile time
+///
+/// `near-multisig build` passes it in `MULTISIG_GIT_COMMIT`; reproducible cargo-near builds
+/// carry it as the `rev` of their NEP-330 source snapshot (`git+<repo>?rev=<commit>`).
+pub fn build_commit() -> &'static str {
+    if let Some(commit) = option_env!("MULTISIG_GIT_COMMIT") {
+        return commit;
+    }
+    option_env!("NEP330_BUILD_INFO_SOURCE_CODE_SNAPSHOT")
+        .and_then(|snapshot| snapshot.rsplit_once("rev="))
+        .map(|(_, rev)| rev)
+        .unwrap_or("unknown")
+}
 
 /// Validates a list of actions and calculates total deposit needed
 ///
 /// Performs comprehensive validation to ensure:
 /// - Action count is within limits
-/// - Gas allocation is reasonable
+/// - Gas allocation is reasonable (each function call gets at least `min_gas`)
 /// - Arguments are appropriately sized
+/// - No transfer amount or function call deposit exceeds `max_deposit`, when set
 /// - All parameters are valid
 ///
 /// Returns the total NEAR deposit required across all actions
-pub fn validate_actions(actions: &Vec<Action>) -> u128 {
+pub fn validate_actions(actions: &Vec<Action>, min_gas: u64, max_deposit: Option<u128>) -> u128 {
     require!(!actions.is_empty(), "Actions cannot be empty");
     require!(actions.len() <= MAX_ACTIONS, "Too many actions (max 10)");
 
@@ -72,6 +272,7 @@ pub fn validate_actions(actions: &Vec<Action>) -> u128 {
         match action {
             Action::Transfer { amount } => {
                 require!(*amount > 0, "Transfer amount must be positive");
+                require_within_max_deposit(*amount, max_deposit);
                 // Use saturating_add to prevent overflow attacks
                 total_deposit = total_deposit.saturating_add(*amount);
             }
@@ -91,6 +292,10 @@ pub fn validate_actions(actions: &Vec<Action>) -> u128 {
 
                 // Ensure reasonable gas allocation
                 require!(*gas > 0, "Gas must be positive");
+                require!(
+                    *gas >= min_gas,
+                    format!("Gas per function call too low (min {} gas)", min_gas)
+                );
                 require!(
                     *gas <= MAX_GAS_PER_ACTION,
                     "Gas per action exceeds limit (max 100 TGas)"
@@ -98,8 +303,27 @@ pub fn validate_actions(actions: &Vec<Action>) -> u128 {
                 total_gas = total_gas.saturating_add(*gas);
 
                 // Accumulate all deposits
+                require_within_max_deposit(*deposit, max_deposit);
                 total_deposit = total_deposit.saturating_add(*deposit);
             }
+            Action::FtTransfer { amount, memo, .. } => {
+                require!(amount.0 > 0, "Token transfer amount must be positive");
+                require!(
+                    memo.as_ref().is_none_or(|memo| memo.len() <= MAX_FT_MEMO_LEN),
+                    "Memo too long (max 256 bytes)"
+                );
+                total_gas = total_gas.saturating_add(FT_TRANSFER_GAS);
+                total_deposit = total_deposit.saturating_add(FT_TRANSFER_DEPOSIT);
+            }
+            Action::NftTransfer { token_id, memo, .. } => {
+                require!(!token_id.is_empty(), "NFT token_id cannot be empty");
+                require!(
+                    memo.as_ref().is_none_or(|memo| memo.len() <= MAX_FT_MEMO_LEN),
+                    "Memo too long (max 256 bytes)"
+                );
+                total_gas = total_gas.saturating_add(NFT_TRANSFER_GAS);
+                total_deposit = total_deposit.saturating_add(NFT_TRANSFER_DEPOSIT);
+            }
         }
     }
 
@@ -111,6 +335,119 @@ pub fn validate_actions(actions: &Vec<Action>) -> u128 {
     total_deposit
 }
 
+/// Panics if one action's attached NEAR exceeds the governance-set `max_deposit_per_action`
+fn require_within_max_deposit(deposit: u128, max_deposit: Option<u128>) {
+    if let Some(max_deposit) = max_deposit {
+        require!(
+            deposit <= max_deposit,
+            format!(
+                "Action deposit exceeds the maximum of {} yoctoNEAR per action",
+                max_deposit
+            )
+        );
+    }
+}
+
+/// Checks that every token transfer targets the transaction's receiver
+///
+/// All actions of a transaction run as one batch on `receiver_id`, so an `FtTransfer` or
+/// `NftTransfer` can only move tokens of the receiver contract itself.
+pub fn validate_action_receivers(receiver_id: &AccountId, actions: &[Action]) {
+    for action in actions {
+        match action {
+            Action::FtTransfer { token_id, .. } => require!(
+                token_id == receiver_id,
+                "FtTransfer token_id must be the transaction receiver"
+            ),
+            Action::NftTransfer { contract_id, .. } => require!(
+                contract_id == receiver_id,
+                "NftTransfer contract_id must be the transaction receiver"
+            ),
+            Action::Transfer { .. } | Action::FunctionCall { .. } => {}
+        }
+    }
+}
+
+/// Validates a transaction category tag
+pub fn validate_category(category: &str) {
+    require!(!category.is_empty(), "Category cannot be empty");
+    require!(
+        category.len() <= MAX_CATEGORY_LEN,
+        "Category too long (max 32 characters)"
+    );
+}
+
+/// Validates the method scope of a function-call access key added to the contract account
+///
+/// An empty list would allow every method, including the `#[private]` ones, so at least one
+/// method is required and none may be private.
+pub fn validate_key_method_names(method_names: &[String]) {
+    require!(
+        !method_names.is_empty(),
+        "Access key must be limited to at least one method"
+    );
+    for name in method_names {
+        require!(
+            !name.is_empty() && name.len() <= MAX_METHOD_NAME_LEN,
+            "Invalid method name for access key"
+        );
+        require!(
+            !name.contains(','),
+            "Access key method names cannot contain commas"
+        );
+        require!(
+            !GOVERNANCE_METHODS.contains(&name.as_str())
+                && !INTERNAL_METHODS.contains(&name.as_str()),
+            format!("Access key cannot be scoped to private method {}", name)
+        );
+    }
+}
+
+/// Estimates the bytes one stored transaction occupies across the transaction collections and
+/// the submitter index
+///
+/// Assumes a single function call action with `ESTIMATE_ARGS_LEN` bytes of args, a short
+/// method name, an expiration, and account IDs of `ESTIMATE_ACCOUNT_ID_LEN` bytes. Real
+/// transactions with large args, many actions or long account IDs cost more. Confirmations are a
+/// fixed-size bitmap, so the threshold doesn't affect the estimate.
+pub fn estimate_transaction_storage_bytes() -> u64 {
+    let account_id = 4 + ESTIMATE_ACCOUNT_ID_LEN; // borsh string: u32 length + bytes
+    let action = 1 + (4 + 16) + (4 + ESTIMATE_ARGS_LEN) + 8 + 16; // tag, method, args, gas, deposit
+
+    let transaction = 8 // id
+        + account_id // receiver_id
+        + 4 + action // actions
+        + 8 // confirmations bitmap
+        + 1 + 1 // executed, cancelled
+        + account_id // storage_depositor
+        + 1 + 8 // expiration
+        + 1 // execute_after (usually unset)
+        + 16 // funded_amount
+        + 4 + 1 // retry_count, last_execution_failed
+        + 1 + 8 // finalized_at
+        + 16 + 1 // executor_reward, executor (unset until execution)
+        + 1 // category (usually unset)
+        + 4 // cancel_votes (usually empty)
+        + 1 // relayer (unset unless relayed)
+        + 1 // in_progress
+        + 1 // deposit_exempt
+        + 4 // action_results (empty until executed)
+        + 1 // require_receiver_ack
+        + 1 // depends_on (usually unset)
+        + 8; // submitted_at
+
+    // tx_ids entry (prefix + u32 index -> u64 id)
+    let ids_record = (1 + 4) + 8;
+    // tx_by_id entry (prefix + u64 id -> transaction)
+    let tx_record = (1 + 8) + transaction;
+    // tx_index entry (prefix + u64 id -> u32 index)
+    let index_record = (1 + 8) + 4;
+    // submitter_index list entry (prefix + account hash + u32 index -> u64 id)
+    let submitter_record = (1 + 32 + 4) + 8;
+
+    ids_record + tx_record + index_record + submitter_record + 4 * STORAGE_RECORD_OVERHEAD
+}
+
 /// Calculates total NEAR deposit needed for a transaction
 ///
 /// Sums all transfer amounts and function call deposits.
@@ -120,5 +457,79 @@ pub fn calculate_transaction_deposit(tx: &crate::types::Transaction) -> u128 {
     tx.actions.iter().fold(0u128, |acc, action| match action {
         Action::Transfer { amount } => acc.saturating_add(*amount),
         Action::FunctionCall { deposit, .. } => acc.saturating_add(*deposit),
+        Action::FtTransfer { .. } => acc.saturating_add(FT_TRANSFER_DEPOSIT),
+        Action::NftTransfer { .. } => acc.saturating_add(NFT_TRANSFER_DEPOSIT),
     })
 }
+
+/// Estimates callback gas that comfortably settles `tx`'s execution
+///
+/// Base bookkeeping plus a per-action amount (more for function calls), the executor reward
+/// transfer if any, and the refund callback's own gas, which the callback attaches. Clamped to
+/// the range `set_callback_gas` accepts.
+pub fn recommended_callback_gas(tx: &crate::types::Transaction, refund_callback_gas: u64) -> u64 {
+    let actions_gas = tx.actions.iter().fold(0u64, |acc, action| {
+        let gas = match action {
+            Action::Transfer { .. } => CALLBACK_GAS_PER_ACTION,
+            Action::FunctionCall { .. }
+            | Action::FtTransfer { .. }
+            | Action::NftTransfer { .. } => CALLBACK_GAS_PER_ACTION + CALLBACK_GAS_PER_CALL,
+        };
+        acc.saturating_add(gas)
+    });
+    let reward_gas = if tx.executor_reward > 0 {
+        CALLBACK_REWARD_GAS
+    } else {
+        0
+    };
+    let refund_gas = if tx.deposit_exempt {
+        0
+    } else {
+        refund_callback_gas
+    };
+
+    CALLBACK_BASE_GAS
+        .saturating_add(actions_gas)
+        .saturating_add(reward_gas)
+        .saturating_add(refund_gas)
+        .clamp(MIN_CALLBACK_GAS, MAX_CALLBACK_GAS)
+}
+
+/// Gas the transaction's actions are dispatched with
+pub fn actions_gas(actions: &[Action]) -> u64 {
+    actions.iter().fold(0u64, |acc, action| match action {
+        Action::FunctionCall { gas, .. } => acc.saturating_add(*gas),
+        Action::FtTransfer { .. } => acc.saturating_add(FT_TRANSFER_GAS),
+        Action::NftTransfer { .. } => acc.saturating_add(NFT_TRANSFER_GAS),
+        Action::Transfer { .. } => acc,
+    })
+}
+
+/// Minimum gas to attach to `execute_transaction` for `tx`, capped at `MAX_PREPAID_GAS`
+///
+/// Sums the actions' gas, the execution callback, the storage refund callback (unless the
+/// transaction is deposit-exempt), the receiver acknowledgement round trip if required, and
+/// `EXECUTE_OVERHEAD_GAS`.
+pub fn min_execute_gas(
+    tx: &crate::types::Transaction,
+    callback_gas: u64,
+    refund_callback_gas: u64,
+) -> u64 {
+    let refund_gas = if tx.deposit_exempt {
+        0
+    } else {
+        refund_callback_gas
+    };
+    let ack_gas = if tx.require_receiver_ack {
+        RECEIVER_ACK_GAS + RECEIVER_ACK_CALLBACK_GAS
+    } else {
+        0
+    };
+
+    EXECUTE_OVERHEAD_GAS
+        .saturating_add(actions_gas(&tx.actions))
+        .saturating_add(callback_gas)
+        .saturating_add(refund_gas)
+        .saturating_add(ack_gas)
+        .min(MAX_PREPAID_GAS)
+}
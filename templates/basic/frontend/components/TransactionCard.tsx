@@ -17,7 +17,8 @@ export default function TransactionCard({
   currentUser,
 }: TransactionCardProps) {
   const hasConfirmed = currentUser && transaction.confirmations.includes(currentUser)
-  const canApprove = currentUser && !hasConfirmed && !transaction.executed
+  const canApprove =
+    currentUser && !hasConfirmed && !transaction.executed && !transaction.in_progress
   const confirmationProgress = `${transaction.confirmations.length}/${threshold}`
 
   // Get action description
@@ -50,6 +51,10 @@ export default function TransactionCard({
             <span className="px-3 py-1 bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200 rounded-full text-sm font-medium">
               Executed
             </span>
+          ) : transaction.in_progress ? (
+            <span className="px-3 py-1 bg-blue-100 text-blue-800 dark:bg-blue-900 dark:text-blue-200 rounded-full text-sm font-medium">
+              Executing
+            </span>
           ) : (
             <span className="px-3 py-1 bg-yellow-100 text-yellow-800 dark:bg-yellow-900 dark:text-yellow-200 rounded-full text-sm font-medium">
               Pending
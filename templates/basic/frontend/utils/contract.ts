@@ -9,8 +9,44 @@ export interface Transaction {
   cancelled: boolean
   storage_depositor: string
   expiration: number | null
+  in_progress: boolean
+  action_results: boolean[]
+  require_receiver_ack: boolean
+  depends_on: number | null
+  submitted_at: number
 }
 
+// From `get_dependency_status`; state is null once the dependency has been cleaned up
+// From `preview_execution`: one promise action as it will be dispatched
+export interface ActionPreview {
+  kind: 'Transfer' | 'FunctionCall'
+  receiver_id: string
+  deposit: string
+  deposit_near: string
+  method_name: string | null
+  args: string | null  // UTF-8 args, null when binary
+  gas: number | null
+}
+
+// From `get_execution_readiness`: the same checks `execute_transaction` enforces
+export interface ExecutionReadiness {
+  effective_confirmations: number
+  required: number
+  stale_confirmers: number
+  is_expired: boolean
+  is_ready: boolean
+  blocking_reason: string | null
+}
+
+export interface DependencyStatus {
+  depends_on: number
+  state: string | null
+  satisfied: boolean
+}
+
+// As returned by the contract: `confirmations` is a bitmap over owner slots
+type RawTransaction = Omit<Transaction, 'confirmations'> & { confirmations: number }
+
 export interface Action {
   Transfer?: { amount: string }
   FunctionCall?: {
@@ -19,6 +55,35 @@ export interface Action {
     gas: number
     deposit: string
   }
+  FtTransfer?: {
+    token_id: string
+    receiver_id: string
+    amount: string
+    memo: string | null
+  }
+  NftTransfer?: {
+    contract_id: string
+    receiver_id: string
+    token_id: string
+    memo: string | null
+  }
+}
+
+// Validation limits from `get_limits`, for checking proposals before submitting
+export interface Limits {
+  max_actions: number
+  max_args_len: number
+  max_method_name_len: number
+  max_gas_per_action: number
+  max_total_gas: number
+  min_function_call_gas: number
+  max_owners: number
+  max_transactions: number
+  max_transaction_value: string | null
+  max_categories: number
+  max_category_len: number
+  max_executor_reward: string
+  max_deposit_per_action: string | null
 }
 
 export class MultisigContract {
@@ -28,19 +93,50 @@ export class MultisigContract {
   ) {}
 
   async getPendingTransactions(fromIndex: number = 0, limit: number = 100): Promise<Transaction[]> {
-    return this.account.viewFunction({
+    const raw: RawTransaction[] = await this.account.viewFunction({
       contractId: this.contractId,
       methodName: 'get_pending_transactions_paginated',
       args: { from_index: fromIndex, limit },
     })
+    return Promise.all(raw.map((tx) => this.withConfirmers(tx)))
   }
 
   async getTransaction(txId: number): Promise<Transaction | null> {
-    return this.account.viewFunction({
+    const raw: RawTransaction | null = await this.account.viewFunction({
       contractId: this.contractId,
       methodName: 'get_transaction',
       args: { tx_id: txId },
     })
+    return raw ? this.withConfirmers(raw) : null
+  }
+
+  // Base64 borsh bytes of the stored transaction, for independent hashing or verification
+  async getTransactionBorsh(txId: number): Promise<string | null> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_transaction_borsh',
+      args: { tx_id: txId },
+    })
+  }
+
+  async getConfirmations(txId: number): Promise<string[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_confirmations',
+      args: { tx_id: txId },
+    })
+  }
+
+  async getNonConfirmers(txId: number): Promise<string[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_non_confirmers',
+      args: { tx_id: txId },
+    })
+  }
+
+  private async withConfirmers(tx: RawTransaction): Promise<Transaction> {
+    return { ...tx, confirmations: await this.getConfirmations(tx.id) }
   }
 
   async getOwners(): Promise<string[]> {
@@ -51,6 +147,15 @@ export class MultisigContract {
     })
   }
 
+  // Owners with no activity since `inactiveSinceNs` (nanosecond timestamp)
+  async getInactiveOwners(inactiveSinceNs: number): Promise<string[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_inactive_owners',
+      args: { inactive_since_ns: inactiveSinceNs },
+    })
+  }
+
   async getNumConfirmations(): Promise<number> {
     return this.account.viewFunction({
       contractId: this.contractId,
@@ -68,6 +173,17 @@ export class MultisigContract {
     })
   }
 
+  // Skips transactions that aren't pending or are already confirmed; returns the IDs confirmed
+  async confirmTransactions(txIds: number[]): Promise<number[]> {
+    const result = await this.account.functionCall({
+      contractId: this.contractId,
+      methodName: 'confirm_transactions',
+      args: { tx_ids: txIds },
+      gas: '100000000000000',
+    })
+    return result as any
+  }
+
   async submitTransaction(
     receiverId: string,
     actions: Action[],
@@ -79,8 +195,106 @@ export class MultisigContract {
       args: {
         receiver_id: receiverId,
         actions,
+        options: { expiration_hours: expirationHours ?? null }
+      },
+      gas: '30000000000000',
+      attachedDeposit: '10000000000000000000000', // 0.01 NEAR storage deposit
+    })
+    return result as any
+  }
+
+  // Gas to attach to execute_transaction; less risks a failed execution
+  async getMinExecuteGas(txId: number): Promise<number> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_min_execute_gas',
+      args: { tx_id: txId },
+    })
+  }
+
+  // Compare against getCallbackGas() and warn when the configured value is lower
+  async getRecommendedCallbackGas(txId: number): Promise<number> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_recommended_callback_gas',
+      args: { tx_id: txId },
+    })
+  }
+
+  // "<git commit>+state.<STATE_VERSION>"; match against the release's build-manifest.json
+  async getBinaryVersion(): Promise<string> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_binary_version',
+      args: {},
+    })
+  }
+
+  async getCallbackGas(): Promise<number> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_callback_gas',
+      args: {},
+    })
+  }
+
+  async previewExecution(txId: number): Promise<ActionPreview[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'preview_execution',
+      args: { tx_id: txId },
+    })
+  }
+
+  async getExecutionReadiness(txId: number): Promise<ExecutionReadiness> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_execution_readiness',
+      args: { tx_id: txId },
+    })
+  }
+
+  async getDependencyStatus(txId: number): Promise<DependencyStatus | null> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_dependency_status',
+      args: { tx_id: txId },
+    })
+  }
+
+  async submitBatchTransfers(
+    payments: [string, string][],
+    expirationHours?: number
+  ): Promise<number[]> {
+    const result = await this.account.functionCall({
+      contractId: this.contractId,
+      methodName: 'submit_batch_transfers',
+      args: {
+        payments,
         expiration_hours: expirationHours ?? null
       },
+      gas: '300000000000000',
+      // 0.01 NEAR storage deposit per transaction
+      attachedDeposit: (10000000000000000000000n * BigInt(payments.length)).toString(),
+    })
+    return result as any
+  }
+
+  async submitKeyRotation(
+    oldKey: string,
+    newKey: string,
+    methodNames: string[],
+    allowance?: string
+  ): Promise<number> {
+    const result = await this.account.functionCall({
+      contractId: this.contractId,
+      methodName: 'submit_key_rotation',
+      args: {
+        old_key: oldKey,
+        new_key: newKey,
+        allowance: allowance ?? null,
+        method_names: methodNames
+      },
       gas: '30000000000000',
       attachedDeposit: '10000000000000000000000', // 0.01 NEAR storage deposit
     })
@@ -106,11 +320,13 @@ export class MultisigContract {
   }
 
   async executeTransaction(txId: number): Promise<void> {
+    // At least 100 TGas, more if the transaction's actions and callbacks need it
+    const minGas = await this.getMinExecuteGas(txId)
     await this.account.functionCall({
       contractId: this.contractId,
       methodName: 'execute_transaction',
       args: { tx_id: txId },
-      gas: '100000000000000', // 100 TGas for execution
+      gas: String(Math.max(minGas, 100_000_000_000_000)),
     })
   }
 
@@ -141,6 +357,32 @@ export class MultisigContract {
     })
   }
 
+  async getLimits(): Promise<Limits> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_limits',
+      args: {},
+    })
+  }
+
+  // Empty when the receiver has no allow-list, i.e. any method may be proposed
+  async getAllowedMethods(receiverId: string): Promise<string[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_allowed_methods',
+      args: { receiver_id: receiverId },
+    })
+  }
+
+  // Action kinds (keys of `Action`) this deployment can execute; older contracts lack newer ones
+  async getSupportedActions(): Promise<string[]> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_supported_actions',
+      args: {},
+    })
+  }
+
   async getStorageDeposit(): Promise<string> {
     return this.account.viewFunction({
       contractId: this.contractId,
@@ -158,4 +400,15 @@ export class MultisigContract {
     })
     return result as any
   }
+
+  // Cancels expired (or older than max_proposal_lifetime) pending transactions
+  async purgeExpiredTransactions(txIds: number[]): Promise<number[]> {
+    const result = await this.account.functionCall({
+      contractId: this.contractId,
+      methodName: 'purge_expired_transactions',
+      args: { tx_ids: txIds },
+      gas: '100000000000000',
+    })
+    return result as any
+  }
 }
@@ -0,0 +1,14 @@
+#!/bin/bash
+set -e
+
+echo "Building basic multisig contract..."
+
+# Build the contract
+cargo near build
+
+echo ""
+echo "✓ Build complete!"
+echo "WASM file: target/near/basic_multisig_standalone.wasm"
+echo ""
+echo "Deploy with:"
+echo "  near deploy --accountId your-multisig.testnet --wasmFile target/near/basic_multisig_standalone.wasm"
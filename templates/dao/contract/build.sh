@@ -0,0 +1,14 @@
+#!/bin/bash
+set -e
+
+echo "Building DAO multisig contract..."
+
+# Build the contract
+cargo near build
+
+echo ""
+echo "✓ Build complete!"
+echo "WASM file: target/near/dao_multisig.wasm"
+echo ""
+echo "Deploy with:"
+echo "  near deploy --accountId your-multisig.testnet --wasmFile target/near/dao_multisig.wasm"
@@ -737,6 +737,28 @@ impl TimelockMultisig {
             .collect()
     }
 
+    /// Get the earliest `scheduled_time` among live scheduled transactions in a window
+    /// Skips executed, cancelled and expired transactions; a time in the past means one is
+    /// executable now. Returns None if nothing in the window is scheduled, so execution
+    /// schedulers can sleep until the returned time instead of polling
+    pub fn get_next_execution_time(&self, from_index: u64, limit: u64) -> Option<u64> {
+        let current_time = env::block_timestamp();
+        let len = self.transactions.len() as u64;
+        let start = from_index.min(len);
+        let end = (start.saturating_add(limit)).min(len);
+
+        (start..end)
+            .filter_map(|i| {
+                let tx = self.transactions.get(i as u32)?;
+                let expired = tx.expiration.is_some_and(|exp_time| current_time >= exp_time);
+                if tx.executed || tx.cancelled || expired {
+                    return None;
+                }
+                tx.scheduled_time
+            })
+            .min()
+    }
+
     pub fn get_transaction_count(&self) -> u64 {
         self.transactions.len() as u64
     }
@@ -753,3 +775,56 @@ impl TimelockMultisig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, VMContext};
+
+    const NANOS_PER_HOUR: u64 = 3_600_000_000_000;
+
+    fn get_context(predecessor: AccountId, block_timestamp: u64) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor)
+            .block_timestamp(block_timestamp)
+            .attached_deposit(NearToken::from_yoctonear(TRANSACTION_STORAGE_DEPOSIT))
+            .build()
+    }
+
+    /// Submits a transfer as alice at `block_timestamp` and has bob confirm it, scheduling it
+    fn submit_scheduled(contract: &mut TimelockMultisig, block_timestamp: u64, expiration_hours: Option<u64>) -> u64 {
+        testing_env!(get_context(accounts(0), block_timestamp));
+        let actions = vec![Action::Transfer { amount: 1000 }];
+        let tx_id = contract.submit_transaction(accounts(3), actions, expiration_hours);
+        testing_env!(get_context(accounts(1), block_timestamp));
+        contract.confirm_transaction(tx_id);
+        tx_id
+    }
+
+    #[test]
+    fn test_next_execution_time() {
+        testing_env!(get_context(accounts(0), 1_000));
+        let mut contract = TimelockMultisig::new(vec![accounts(0), accounts(1)], 2, MIN_TIMELOCK);
+        assert_eq!(contract.get_next_execution_time(0, 10), None);
+
+        // Unconfirmed transactions aren't scheduled yet
+        let actions = vec![Action::Transfer { amount: 1000 }];
+        contract.submit_transaction(accounts(3), actions, None);
+        assert_eq!(contract.get_next_execution_time(0, 10), None);
+
+        let expiring = submit_scheduled(&mut contract, 1_000, Some(1));
+        let later = submit_scheduled(&mut contract, 2_000, None);
+        assert_eq!(contract.get_next_execution_time(0, 10), Some(1_000 + MIN_TIMELOCK));
+        // Only the requested window is considered
+        assert_eq!(contract.get_next_execution_time(later, 10), Some(2_000 + MIN_TIMELOCK));
+        assert_eq!(contract.get_next_execution_time(0, expiring), None);
+
+        // Expired transactions are skipped; a past time means executable now
+        testing_env!(get_context(accounts(0), 1_000 + NANOS_PER_HOUR));
+        assert_eq!(contract.get_next_execution_time(0, 10), Some(2_000 + MIN_TIMELOCK));
+
+        contract.cancel_transaction(later);
+        assert_eq!(contract.get_next_execution_time(0, 10), None);
+    }
+}
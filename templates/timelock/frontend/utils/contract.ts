@@ -45,6 +45,15 @@ export class TimelockMultisigContract {
     })
   }
 
+  // Earliest scheduled_time (ns) of a live scheduled transaction, or null if none
+  async getNextExecutionTime(fromIndex: number = 0, limit: number = 100): Promise<number | null> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_next_execution_time',
+      args: { from_index: fromIndex, limit },
+    })
+  }
+
   async getTransaction(txId: number): Promise<Transaction | null> {
     return this.account.viewFunction({
       contractId: this.contractId,
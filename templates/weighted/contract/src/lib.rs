@@ -1,5 +1,5 @@
 use near_sdk::store::{UnorderedMap, Vector};
-use near_sdk::{near, require, AccountId, PanicOnDefault, env, Promise, NearToken, Gas, PromiseResult};
+use near_sdk::{near, require, AccountId, PanicOnDefault, env, Promise, PromiseOrValue, NearToken, Gas, PromiseResult};
 
 /// Security: Maximum number of actions per transaction to prevent gas exhaustion
 const MAX_ACTIONS: usize = 10;
@@ -28,6 +28,10 @@ const TRANSACTION_STORAGE_DEPOSIT: u128 = 10_000_000_000_000_000_000_000; // 0.0
 /// Default callback gas (20 TGas) - can be configured per contract
 const DEFAULT_CALLBACK_GAS: u64 = 20_000_000_000_000;
 
+/// Methods a self-targeted transaction may call (all `#[private]`)
+/// Callbacks must never be self-invoked, so they aren't listed
+const GOVERNANCE_METHODS: [&str; 2] = ["set_owner_weight", "set_approval_threshold"];
+
 #[near(contract_state)]
 #[derive(PanicOnDefault)]
 pub struct WeightedMultisig {
@@ -47,12 +51,50 @@ pub struct Transaction {
     pub id: u64,
     pub receiver_id: AccountId,
     pub actions: Vec<Action>,
-    pub approvals: Vec<(AccountId, u32)>, // (owner, weight)
-    pub total_weight: u32,
+    pub approvals: Vec<(AccountId, u32)>, // (owner, weight when approved)
+    pub total_weight: u32, // Approvals recounted at current weights when last approved, revoked or executed
     pub executed: bool,
     pub cancelled: bool,
     pub storage_depositor: AccountId, // Who paid storage deposit (gets refund)
     pub expiration: Option<u64>, // L-2 fix: Optional expiration timestamp (nanoseconds)
+    pub cancel_votes: Vec<AccountId>, // Owners who voted to force-cancel (counted at their current weight)
+}
+
+/// `Transaction` layout from before force-cancel votes, read only by `migrate()`
+/// Frozen: it must match what deployed contracts hold, so never add fields here
+#[near(serializers = [borsh])]
+pub struct LegacyTransaction {
+    pub id: u64,
+    pub receiver_id: AccountId,
+    pub actions: Vec<Action>,
+    pub approvals: Vec<(AccountId, u32)>,
+    pub total_weight: u32,
+    pub executed: bool,
+    pub cancelled: bool,
+    pub storage_depositor: AccountId,
+    pub expiration: Option<u64>,
+}
+
+/// State layout whose transactions predate force-cancel votes, read only by `migrate()`
+#[near(serializers = [borsh])]
+struct OldState {
+    pub owner_weights: UnorderedMap<AccountId, u32>,
+    pub approval_threshold: u32,
+    pub transactions: Vector<LegacyTransaction>,
+    pub pending_callbacks: u32,
+    pub callback_gas: u64,
+    pub storage_deposit: u128,
+    pub next_tx_id: u64,
+    pub reserved_balance: u128,
+}
+
+/// Voting power per owner, returned by `get_weight_distribution`
+#[near(serializers = [json])]
+pub struct WeightDistribution {
+    /// Same as `get_total_weight`; None on overflow, in which case every bps is 0
+    pub total_weight: Option<u32>,
+    /// (owner, weight, basis points of total weight)
+    pub owners: Vec<(AccountId, u32, u32)>,
 }
 
 #[near(serializers = [json, borsh])]
@@ -85,12 +127,21 @@ pub enum MultisigEvent {
     #[event_version("1.0.0")]
     ApprovalRevoked { tx_id: u64, revoker: AccountId, weight: u32, total_weight: u32 },
 
+    #[event_version("1.0.0")]
+    CancelVoteCast { tx_id: u64, voter: AccountId, weight: u32, total_weight: u32 },
+
     #[event_version("1.0.0")]
     CallbackGasChanged { old_gas: u64, new_gas: u64, changer: AccountId },
 
     #[event_version("1.0.0")]
     StorageDepositChanged { old_deposit: u128, new_deposit: u128, changer: AccountId },
 
+    #[event_version("1.0.0")]
+    OwnerWeightChanged { account: AccountId, old_weight: u32, new_weight: u32, total_weight: u32, changer: AccountId },
+
+    #[event_version("1.0.0")]
+    ApprovalThresholdChanged { old: u32, new: u32, changer: AccountId },
+
     #[event_version("1.0.0")]
     ManualExecutionTriggered { tx_id: u64, executor: AccountId },
 
@@ -170,6 +221,33 @@ impl WeightedMultisig {
         total_deposit
     }
 
+    /// Panics unless called by the contract itself, i.e. by an approved self-targeted transaction
+    /// Mirrors `#[private]`, which only guards calls arriving through the wasm entry point
+    fn require_self() {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Only callable through an approved multisig transaction"
+        );
+    }
+
+    /// Current weight behind a transaction's approvals
+    /// Approvers who are no longer owners count for nothing, like in `cancel_weight`
+    fn approval_weight(&self, tx: &Transaction) -> u32 {
+        tx.approvals
+            .iter()
+            .filter_map(|(approver, _)| self.owner_weights.get(approver))
+            .fold(0u32, |acc, w| acc.saturating_add(*w))
+    }
+
+    /// Current weight behind a transaction's force-cancel votes
+    /// Voters who are no longer owners count for nothing
+    fn cancel_weight(&self, tx: &Transaction) -> u32 {
+        tx.cancel_votes
+            .iter()
+            .filter_map(|voter| self.owner_weights.get(voter))
+            .fold(0u32, |acc, w| acc.saturating_add(*w))
+    }
+
     /// M-2 fix: Calculate total deposit for a transaction (helper for balance tracking)
     fn calculate_transaction_deposit(tx: &Transaction) -> u128 {
         tx.actions.iter().fold(0u128, |acc, action| {
@@ -179,6 +257,26 @@ impl WeightedMultisig {
             }
         })
     }
+
+    /// Marks a transaction cancelled, releases its reserved balance and refunds the depositor
+    fn finalize_cancel(&mut self, mut tx: Transaction, canceller: AccountId) -> Promise {
+        // M-2 fix: Release reserved balance when cancelling
+        let deposit = Self::calculate_transaction_deposit(&tx);
+        self.reserved_balance = self.reserved_balance.saturating_sub(deposit);
+
+        tx.cancelled = true;
+        self.transactions.replace(self.get_tx_index_or_panic(tx.id), tx.clone());
+
+        // Emit cancellation event
+        MultisigEvent::TransactionCancelled {
+            tx_id: tx.id,
+            canceller,
+        }.emit();
+
+        // Refund always goes to the original submitter, even if they're no longer an owner
+        Promise::new(tx.storage_depositor)
+            .transfer(NearToken::from_yoctonear(self.storage_deposit))
+    }
 }
 
 #[near]
@@ -220,6 +318,44 @@ impl WeightedMultisig {
         }
     }
 
+    /// Upgrades state written before force-cancel votes were added
+    /// Rewrites every stored transaction with no cancel votes; call once, right after deploying
+    /// this code over such a contract
+    #[init(ignore_state)]
+    #[private]
+    pub fn migrate() -> Self {
+        let old: OldState = env::state_read().expect("Failed to read old state");
+
+        // Same prefix as `new()`, so each entry is overwritten in place
+        let mut transactions = Vector::new(b"t");
+        for legacy in old.transactions.iter() {
+            transactions.push(Transaction {
+                id: legacy.id,
+                receiver_id: legacy.receiver_id.clone(),
+                actions: legacy.actions.clone(),
+                approvals: legacy.approvals.clone(),
+                total_weight: legacy.total_weight,
+                executed: legacy.executed,
+                cancelled: legacy.cancelled,
+                storage_depositor: legacy.storage_depositor.clone(),
+                expiration: legacy.expiration,
+                cancel_votes: Vec::new(),
+            });
+        }
+        env::log_str(&format!("Migration complete. Migrated {} transactions.", transactions.len()));
+
+        Self {
+            owner_weights: old.owner_weights,
+            approval_threshold: old.approval_threshold,
+            transactions,
+            pending_callbacks: old.pending_callbacks,
+            callback_gas: old.callback_gas,
+            storage_deposit: old.storage_deposit,
+            next_tx_id: old.next_tx_id,
+            reserved_balance: old.reserved_balance,
+        }
+    }
+
     // ===== Write Methods =====
 
     /// Submit a new transaction for approval
@@ -236,11 +372,18 @@ impl WeightedMultisig {
             format!("Must attach at least {} yoctoNEAR for storage", self.storage_deposit)
         );
 
-        // Security: Prevent sending to self
-        require!(
-            receiver_id != env::current_account_id(),
-            "Cannot send to multisig contract itself"
-        );
+        // Security: Prevent sending to self. Only zero-deposit calls to the governance
+        // setters may target the contract itself.
+        if receiver_id == env::current_account_id() {
+            require!(
+                actions.iter().all(|action| matches!(
+                    action,
+                    Action::FunctionCall { method, deposit: 0, .. }
+                        if GOVERNANCE_METHODS.contains(&method.as_str())
+                )),
+                "Cannot send to multisig contract itself"
+            );
+        }
 
         // Security: Validate actions and get total deposit
         let total_deposit = Self::validate_actions(&actions);
@@ -284,6 +427,7 @@ impl WeightedMultisig {
             cancelled: false,
             storage_depositor: sender.clone(),
             expiration,
+            cancel_votes: Vec::new(),
         };
 
         self.transactions.push(tx);
@@ -321,9 +465,7 @@ impl WeightedMultisig {
         );
 
         tx.approvals.push((sender.clone(), *weight));
-
-        // Security: Use checked_add to prevent weight overflow (H-2)
-        tx.total_weight = tx.total_weight.checked_add(*weight).expect("Weight overflow");
+        tx.total_weight = self.approval_weight(&tx);
 
         // Emit approval event
         MultisigEvent::TransactionApproved {
@@ -364,6 +506,8 @@ impl WeightedMultisig {
             );
         }
 
+        // Weight changes since approval count, the same way force-cancel votes are recounted
+        tx.total_weight = self.approval_weight(&tx);
         require!(
             tx.total_weight >= self.approval_threshold,
             "Not enough weight"
@@ -416,6 +560,7 @@ impl WeightedMultisig {
     }
 
     /// Cancel a pending transaction (only submitter can cancel)
+    /// Any owner may cancel once the submitter is no longer an owner, so proposals can't get stuck
     /// Returns a Promise for the storage deposit refund
     pub fn cancel_transaction(&mut self, tx_id: u64) -> Promise {
         let sender = env::predecessor_account_id();
@@ -423,33 +568,52 @@ impl WeightedMultisig {
 
         // MEDIUM-2 fix: Removed u32::MAX check - get_tx() handles any u64 value
 
-        let mut tx = self.get_tx_or_panic(tx_id).clone();
+        let tx = self.get_tx_or_panic(tx_id).clone();
         require!(!tx.executed, "Already executed");
         require!(!tx.cancelled, "Already cancelled");
 
-        // Only the submitter (first approver) can cancel
+        // Only the submitter (who paid the storage deposit) can cancel, unless they've left
         require!(
-            tx.approvals.first().map(|(owner, _)| owner) == Some(&sender),
+            tx.storage_depositor == sender || !self.owner_weights.contains_key(&tx.storage_depositor),
             "Only submitter can cancel"
         );
 
-        // M-2 fix: Release reserved balance when cancelling
-        let deposit = Self::calculate_transaction_deposit(&tx);
-        self.reserved_balance = self.reserved_balance.saturating_sub(deposit);
+        // Security (H-1 fix): Return refund promise instead of detaching
+        // If refund fails, the caller will be notified via promise failure
+        self.finalize_cancel(tx, sender)
+    }
 
-        tx.cancelled = true;
-        self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx.clone());
+    /// Vote to force-cancel a pending transaction without its submitter
+    /// Votes are recounted at the voters' current weights, ignoring voters who are no longer
+    /// owners; the transaction is cancelled once they reach `approval_threshold`, releasing its
+    /// reserved balance and refunding the original depositor
+    /// Returns the refund Promise when this vote cancels the transaction
+    pub fn force_cancel_transaction(&mut self, tx_id: u64) -> PromiseOrValue<()> {
+        let sender = env::predecessor_account_id();
+        let weight = *self.owner_weights.get(&sender).expect("Not an owner");
 
-        // Emit cancellation event
-        MultisigEvent::TransactionCancelled {
+        let mut tx = self.get_tx_or_panic(tx_id).clone();
+        require!(!tx.executed, "Already executed");
+        require!(!tx.cancelled, "Already cancelled");
+        require!(!tx.cancel_votes.contains(&sender), "Already voted to cancel");
+
+        tx.cancel_votes.push(sender.clone());
+        let total_weight = self.cancel_weight(&tx);
+
+        MultisigEvent::CancelVoteCast {
             tx_id,
-            canceller: sender,
+            voter: sender.clone(),
+            weight,
+            total_weight,
         }.emit();
 
-        // Security (H-1 fix): Return refund promise instead of detaching
-        // If refund fails, the caller will be notified via promise failure
-        Promise::new(tx.storage_depositor.clone())
-            .transfer(NearToken::from_yoctonear(self.storage_deposit))
+        if total_weight >= self.approval_threshold {
+            // Security (H-1 fix): Return refund promise instead of detaching
+            PromiseOrValue::Promise(self.finalize_cancel(tx, sender))
+        } else {
+            self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx);
+            PromiseOrValue::Value(())
+        }
     }
 
     /// Update callback gas allocation (owner-only)
@@ -495,6 +659,53 @@ impl WeightedMultisig {
         self.storage_deposit
     }
 
+    /// Change an existing owner's voting weight
+    /// Their approvals on pending transactions count at the new weight from now on
+    /// Governance: only callable through an approved self-targeted `FunctionCall` transaction
+    #[private]
+    pub fn set_owner_weight(&mut self, account: AccountId, weight: u32) {
+        Self::require_self();
+        let sender = env::predecessor_account_id();
+        require!(weight > 0, "Weight must be positive");
+        let old_weight = *self.owner_weights.get(&account).expect("Account is not an owner");
+
+        // Security: Use checked arithmetic to prevent overflow (H-2)
+        let total_weight = self.get_total_weight().expect("Total weight overflow")
+            .checked_sub(old_weight).expect("Weight underflow")
+            .checked_add(weight).expect("Total weight overflow");
+        require!(self.approval_threshold <= total_weight, "Threshold exceeds total weight");
+
+        self.owner_weights.insert(account.clone(), weight);
+
+        MultisigEvent::OwnerWeightChanged {
+            account,
+            old_weight,
+            new_weight: weight,
+            total_weight,
+            changer: sender,
+        }.emit();
+    }
+
+    /// Change the total weight needed to execute
+    /// Governance: only callable through an approved self-targeted `FunctionCall` transaction
+    #[private]
+    pub fn set_approval_threshold(&mut self, threshold: u32) {
+        Self::require_self();
+        let sender = env::predecessor_account_id();
+        require!(threshold > 0, "Threshold must be positive");
+        let total_weight = self.get_total_weight().expect("Total weight overflow");
+        require!(threshold <= total_weight, "Threshold exceeds total weight");
+
+        let old = self.approval_threshold;
+        self.approval_threshold = threshold;
+
+        MultisigEvent::ApprovalThresholdChanged {
+            old,
+            new: threshold,
+            changer: sender,
+        }.emit();
+    }
+
     /// Revoke your approval from a pending transaction
     pub fn revoke_approval(&mut self, tx_id: u64) {
         let sender = env::predecessor_account_id();
@@ -509,10 +720,9 @@ impl WeightedMultisig {
         let pos = tx.approvals.iter().position(|(owner, _)| owner == &sender);
         require!(pos.is_some(), "Not approved by you");
 
-        let (_, weight) = tx.approvals.remove(pos.unwrap());
-
-        // Security: Use checked_sub to prevent underflow
-        tx.total_weight = tx.total_weight.checked_sub(weight).expect("Weight underflow");
+        tx.approvals.remove(pos.unwrap());
+        let weight = *self.owner_weights.get(&sender).expect("Not an owner");
+        tx.total_weight = self.approval_weight(&tx);
 
         let total_weight = tx.total_weight;
         self.transactions.replace(self.get_tx_index_or_panic(tx_id), tx);
@@ -689,6 +899,24 @@ impl WeightedMultisig {
             .try_fold(0u32, |acc, (_, w)| acc.checked_add(*w))
     }
 
+    /// Each owner's weight and share of the total in basis points (10000 = 100%)
+    /// Shares round down, so they may sum to slightly less than 10000
+    pub fn get_weight_distribution(&self) -> WeightDistribution {
+        let total_weight = self.get_total_weight();
+        let owners = self.owner_weights
+            .iter()
+            .map(|(account, weight)| {
+                let bps = total_weight
+                    .filter(|total| *total > 0)
+                    .and_then(|total| (*weight as u64).checked_mul(10_000).map(|w| w / total as u64))
+                    .and_then(|bps| u32::try_from(bps).ok())
+                    .unwrap_or(0);
+                (account.clone(), *weight, bps)
+            })
+            .collect();
+        WeightDistribution { total_weight, owners }
+    }
+
     pub fn get_transaction(&self, tx_id: u64) -> Option<Transaction> {
         self.get_tx(tx_id).cloned()
     }
@@ -717,6 +945,24 @@ impl WeightedMultisig {
         self.transactions.len() as u64
     }
 
+    /// Get the weight an owner has committed to pending transactions within a window (paginated)
+    /// Counts each of the owner's approvals at their current weight, which is what execution counts
+    pub fn get_committed_weight(&self, account_id: AccountId, from_index: u64, limit: u64) -> u32 {
+        let Some(weight) = self.owner_weights.get(&account_id).copied() else {
+            return 0;
+        };
+        let len = self.transactions.len() as u64;
+        let start = from_index.min(len);
+        let end = (start.saturating_add(limit)).min(len);
+
+        // Direct index access keeps this linear in the window size
+        (start..end)
+            .filter_map(|i| self.transactions.get(i as u32))
+            .filter(|tx| !tx.executed && !tx.cancelled)
+            .filter(|tx| tx.approvals.iter().any(|(owner, _)| owner == &account_id))
+            .fold(0u32, |acc, _| acc.saturating_add(weight))
+    }
+
     pub fn is_owner(&self, account_id: AccountId) -> bool {
         self.owner_weights.contains_key(&account_id)
     }
@@ -731,6 +977,240 @@ impl WeightedMultisig {
 
     pub fn get_transaction_progress(&self, tx_id: u64) -> Option<(u32, u32)> {
         self.get_tx(tx_id)
-            .map(|tx| (tx.total_weight, self.approval_threshold))
+            .map(|tx| (self.approval_weight(tx), self.approval_threshold))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::{testing_env, VMContext};
+
+    fn get_context(predecessor: AccountId) -> VMContext {
+        VMContextBuilder::new()
+            .predecessor_account_id(predecessor)
+            .attached_deposit(NearToken::from_yoctonear(TRANSACTION_STORAGE_DEPOSIT))
+            .build()
+    }
+
+    fn setup() -> WeightedMultisig {
+        testing_env!(get_context(accounts(0)));
+        let owners = vec![(accounts(0), 40), (accounts(1), 30), (accounts(2), 30)];
+        WeightedMultisig::new(owners, 60)
+    }
+
+    fn submit(contract: &mut WeightedMultisig) -> u64 {
+        let actions = vec![Action::Transfer { amount: 1000 }];
+        contract.submit_transaction(accounts(3), actions, None)
+    }
+
+    #[test]
+    #[should_panic(expected = "Only submitter can cancel")]
+    fn test_only_submitter_can_cancel() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+
+        testing_env!(get_context(accounts(1)));
+        contract.cancel_transaction(tx_id);
+    }
+
+    #[test]
+    fn test_owner_can_cancel_after_submitter_removed() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+        assert_eq!(contract.reserved_balance, 1000);
+
+        // Submitter leaves the owner set
+        contract.owner_weights.remove(&accounts(0));
+
+        testing_env!(get_context(accounts(1)));
+        contract.cancel_transaction(tx_id);
+
+        let tx = contract.get_transaction(tx_id).unwrap();
+        assert!(tx.cancelled);
+        assert_eq!(tx.storage_depositor, accounts(0));
+        assert_eq!(contract.reserved_balance, 0);
+    }
+
+    #[test]
+    fn test_force_cancel_by_weight() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+
+        testing_env!(get_context(accounts(1)));
+        contract.force_cancel_transaction(tx_id);
+        assert!(!contract.get_transaction(tx_id).unwrap().cancelled);
+
+        // 30 + 30 reaches the threshold of 60
+        testing_env!(get_context(accounts(2)));
+        contract.force_cancel_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().cancelled);
+        assert_eq!(contract.reserved_balance, 0);
+    }
+
+    #[test]
+    fn test_force_cancel_ignores_removed_voters() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+
+        testing_env!(get_context(accounts(1)));
+        contract.force_cancel_transaction(tx_id);
+
+        // accounts(1) leaves, so their vote no longer counts toward the threshold
+        contract.owner_weights.remove(&accounts(1));
+        testing_env!(get_context(accounts(2)));
+        contract.force_cancel_transaction(tx_id);
+        assert!(!contract.get_transaction(tx_id).unwrap().cancelled);
+
+        // 30 + 40 from the remaining owners reaches the threshold of 60
+        testing_env!(get_context(accounts(0)));
+        contract.force_cancel_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().cancelled);
+    }
+
+    #[test]
+    fn test_migrate_adds_empty_cancel_votes() {
+        testing_env!(get_context(accounts(0)));
+
+        let mut owner_weights = UnorderedMap::new(b"w");
+        owner_weights.insert(accounts(0), 40);
+        owner_weights.insert(accounts(1), 30);
+
+        let mut transactions = Vector::new(b"t");
+        for id in 0..2 {
+            transactions.push(LegacyTransaction {
+                id,
+                receiver_id: accounts(3),
+                actions: vec![Action::Transfer { amount: 1000 }],
+                approvals: vec![(accounts(0), 40)],
+                total_weight: 40,
+                executed: id == 0,
+                cancelled: false,
+                storage_depositor: accounts(0),
+                expiration: None,
+            });
+        }
+
+        let mut old_state = OldState {
+            owner_weights,
+            approval_threshold: 60,
+            transactions,
+            pending_callbacks: 0,
+            callback_gas: DEFAULT_CALLBACK_GAS,
+            storage_deposit: TRANSACTION_STORAGE_DEPOSIT,
+            next_tx_id: 2,
+            reserved_balance: 1000,
+        };
+        old_state.owner_weights.flush();
+        old_state.transactions.flush();
+        env::state_write(&old_state);
+
+        let contract = WeightedMultisig::migrate();
+        assert_eq!(contract.get_transaction_count(), 2);
+        assert_eq!(contract.get_owner_weight(accounts(1)), Some(30));
+        assert_eq!(contract.next_tx_id, 2);
+
+        let pending = contract.get_transaction(1).unwrap();
+        assert!(!pending.executed);
+        assert_eq!(pending.total_weight, 40);
+        assert!(pending.cancel_votes.is_empty());
+        assert!(contract.get_transaction(0).unwrap().executed);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already voted to cancel")]
+    fn test_force_cancel_rejects_double_vote() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+
+        testing_env!(get_context(accounts(1)));
+        contract.force_cancel_transaction(tx_id);
+        contract.force_cancel_transaction(tx_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only callable through an approved multisig transaction")]
+    fn test_set_owner_weight_rejects_direct_owner_call() {
+        let mut contract = setup();
+        testing_env!(get_context(accounts(1)));
+        contract.set_owner_weight(accounts(1), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only callable through an approved multisig transaction")]
+    fn test_set_approval_threshold_rejects_direct_owner_call() {
+        let mut contract = setup();
+        testing_env!(get_context(accounts(1)));
+        contract.set_approval_threshold(1);
+    }
+
+    #[test]
+    fn test_weight_change_via_self_transaction() {
+        // The test contract account is accounts(0), so this call arrives from the contract itself
+        let mut contract = setup();
+        contract.set_approval_threshold(70);
+        assert_eq!(contract.get_approval_threshold(), 70);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough weight")]
+    fn test_execution_recounts_approvals_at_current_weight() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+        testing_env!(get_context(accounts(1)));
+        contract.approve_transaction(tx_id);
+        assert_eq!(contract.get_transaction_progress(tx_id), Some((70, 60)));
+
+        // Bob's weight drops after he approved, so his approval now only adds 10
+        testing_env!(get_context(accounts(0)));
+        contract.set_owner_weight(accounts(1), 10);
+        assert_eq!(contract.get_transaction_progress(tx_id), Some((50, 60)));
+        assert_eq!(contract.get_committed_weight(accounts(1), 0, 10), 10);
+
+        contract.execute_transaction(tx_id);
+    }
+
+    #[test]
+    fn test_execution_counts_raised_weight() {
+        let mut contract = setup();
+        let tx_id = submit(&mut contract);
+        assert_eq!(contract.get_transaction_progress(tx_id), Some((40, 60)));
+
+        testing_env!(get_context(accounts(0)));
+        contract.set_owner_weight(accounts(0), 70);
+        contract.execute_transaction(tx_id);
+        assert!(contract.get_transaction(tx_id).unwrap().executed);
+        assert_eq!(contract.get_transaction(tx_id).unwrap().total_weight, 70);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot send to multisig contract itself")]
+    fn test_self_transaction_limited_to_governance_methods() {
+        let mut contract = setup();
+
+        testing_env!(get_context(accounts(1)));
+        let actions = vec![Action::FunctionCall {
+            method: "on_execute_callback".to_string(),
+            args: b"{\"tx_id\":0}".to_vec(),
+            gas: 10_000_000_000_000,
+            deposit: 0,
+        }];
+        contract.submit_transaction(accounts(0), actions, None);
+    }
+
+    #[test]
+    fn test_weight_distribution() {
+        let contract = setup();
+        let distribution = contract.get_weight_distribution();
+        assert_eq!(distribution.total_weight, Some(100));
+
+        let mut owners = distribution.owners;
+        owners.sort();
+        assert_eq!(owners, vec![
+            (accounts(0), 40, 4_000),
+            (accounts(1), 30, 3_000),
+            (accounts(2), 30, 3_000),
+        ]);
     }
 }
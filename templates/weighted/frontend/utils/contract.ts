@@ -18,6 +18,12 @@ export interface Transaction {
   executed: boolean
 }
 
+// From `get_weight_distribution`; total_weight is null (and every bps 0) on overflow
+export interface WeightDistribution {
+  total_weight: number | null
+  owners: [string, number, number][]  // [account_id, weight, bps of total]
+}
+
 export class WeightedMultisigContract {
   constructor(private account: Account, private contractId: string) {}
 
@@ -69,6 +75,14 @@ export class WeightedMultisigContract {
     })
   }
 
+  async getWeightDistribution(): Promise<WeightDistribution> {
+    return this.account.viewFunction({
+      contractId: this.contractId,
+      methodName: 'get_weight_distribution',
+      args: {},
+    })
+  }
+
   async getTransactionProgress(txId: number): Promise<[number, number] | null> {
     return this.account.viewFunction({
       contractId: this.contractId,
@@ -0,0 +1,513 @@
+//! Borsh and JSON types of the basic multisig contract
+//!
+//! The contract (`templates/basic/contract`) uses these types directly with the `near-sdk`
+//! feature, which derives them through `#[near(serializers = ...)]` so they carry ABI schemas.
+//! Integration tests (near-workspaces) and off-chain tooling depend on this crate without the
+//! feature, which keeps `near-sdk` out of their build while sharing the exact same layouts.
+
+#[cfg(not(feature = "near-sdk"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_account_id::AccountId;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "near-sdk")]
+pub use near_sdk::json_types::U128;
+
+/// ed25519 public key; `near_sdk::PublicKey` in the contract, its `ed25519:<base58>` string
+/// form elsewhere
+#[cfg(feature = "near-sdk")]
+pub type PublicKey = near_sdk::PublicKey;
+#[cfg(not(feature = "near-sdk"))]
+pub type PublicKey = String;
+
+/// Prefix NEP-297 events are logged with
+pub const EVENT_LOG_PREFIX: &str = "EVENT_JSON:";
+
+/// NEP-297 standard name every event is emitted under (template customization point)
+#[cfg(not(feature = "treasury-events"))]
+pub const EVENT_STANDARD: &str = "multisig";
+#[cfg(feature = "treasury-events")]
+pub const EVENT_STANDARD: &str = "treasury-multisig";
+
+/// Version every contract event is currently emitted under
+pub const EVENT_VERSION: &str = "1.0.0";
+
+/// Transaction submitted for multisig approval, as returned by `get_transaction`
+#[cfg_attr(feature = "near-sdk", near_sdk::near(serializers = [json, borsh]))]
+#[cfg_attr(
+    not(feature = "near-sdk"),
+    derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize)
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transaction {
+    pub id: u64,
+    pub receiver_id: AccountId,
+    pub actions: Vec<Action>,
+    pub confirmations: u64, // Bitmap over owner slots; resolve with the `get_confirmations` view
+    pub executed: bool,
+    pub cancelled: bool,
+    pub storage_depositor: AccountId, // Who paid storage deposit (gets refund)
+    pub expiration: Option<u64>,      // Optional expiration timestamp (nanoseconds)
+    pub funded_amount: u128,          // NEAR the submitter attached to fund this tx (0 if none)
+    pub execute_after: Option<u64>,   // Optional earliest execution timestamp (nanoseconds)
+    pub retry_count: u32,             // Times execution was retried via retry_transaction
+    pub last_execution_failed: bool,  // Last execution attempt failed (eligible for retry)
+    pub finalized_at: Option<u64>,    // When it was executed or cancelled (nanoseconds)
+    pub executor_reward: u128,        // Paid to the executor on success (reserved until then)
+    pub executor: Option<AccountId>,  // Who triggered the latest execution
+    pub category: Option<String>,     // Reporting tag from the category allow-list
+    pub cancel_votes: Vec<AccountId>, // Owners who voted to cancel (threshold cancellation)
+    pub relayer: Option<AccountId>,   // Paid the storage deposit for a relayed submission
+    pub in_progress: bool,            // Execution promise dispatched, awaiting its callback
+    pub deposit_exempt: bool,         // Submitted without a storage deposit (nothing to refund)
+    pub action_results: Vec<bool>,    // Per action: confirmed applied by the latest execution
+    pub require_receiver_ack: bool,   // Receiver must accept `on_multisig_offer` before execution
+    pub depends_on: Option<u64>,      // Transaction that must have executed before this one
+    pub submitted_at: u64,            // When it was submitted (nanoseconds)
+    pub revoked_confirmations: u64,   // Owners who revoked; signed confirmations can't re-add them
+    pub reservation_released: bool,   // Finalizing released its reserved value (happens only once)
+}
+
+/// Actions that can be performed in a transaction (argument to `submit_transaction`)
+#[cfg_attr(feature = "near-sdk", near_sdk::near(serializers = [json, borsh]))]
+#[cfg_attr(
+    not(feature = "near-sdk"),
+    derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize)
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Transfer {
+        amount: u128,
+    },
+    FunctionCall {
+        method_name: String,
+        args: Vec<u8>,
+        gas: u64,
+        deposit: u128,
+    },
+    /// NEP-141 `ft_transfer` on `token_id`, which must be the transaction's receiver; executed
+    /// with 1 yoctoNEAR attached and `FT_TRANSFER_GAS`
+    FtTransfer {
+        token_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        memo: Option<String>,
+    },
+    /// NEP-171 `nft_transfer` on `contract_id`, which must be the transaction's receiver;
+    /// executed with 1 yoctoNEAR attached and `NFT_TRANSFER_GAS`
+    NftTransfer {
+        contract_id: AccountId,
+        receiver_id: AccountId,
+        token_id: String,
+        memo: Option<String>,
+    },
+}
+
+impl Action {
+    /// Variant names this contract version can execute, as returned by `get_supported_actions`
+    pub const KINDS: [&'static str; 4] = ["Transfer", "FunctionCall", "FtTransfer", "NftTransfer"];
+
+    /// Variant name as it appears in JSON; the exhaustive match keeps `KINDS` honest
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Action::Transfer { .. } => Self::KINDS[0],
+            Action::FunctionCall { .. } => Self::KINDS[1],
+            Action::FtTransfer { .. } => Self::KINDS[2],
+            Action::NftTransfer { .. } => Self::KINDS[3],
+        }
+    }
+}
+
+/// Lifecycle state of a transaction returned by `get_transaction_state`
+#[cfg_attr(feature = "near-sdk", near_sdk::near(serializers = [json]))]
+#[cfg_attr(not(feature = "near-sdk"), derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxLifecycle {
+    /// Awaiting confirmations, or scheduled for later
+    Pending,
+    /// Executable now: threshold met (incl. any mandatory signer) and not scheduled for later
+    Ready,
+    /// Past its expiration without being executed or cancelled
+    Expired,
+    /// Execution promise dispatched, awaiting its callback
+    Executing,
+    Executed,
+    Cancelled,
+}
+
+/// Whether submitted proposals may, must or must not carry an expiration
+#[cfg_attr(feature = "near-sdk", near_sdk::near(serializers = [json, borsh]))]
+#[cfg_attr(
+    not(feature = "near-sdk"),
+    derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize)
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpirationPolicy {
+    /// Submitters choose; `default_expiration_hours` applies when they don't
+    Optional,
+    /// Every proposal needs an explicit or default expiration
+    Required,
+    /// Proposals never expire; `expiration_hours` is rejected
+    Disabled,
+}
+
+/// `u128` that goes over JSON as a decimal string, like `near_sdk::json_types::U128`
+#[cfg(not(feature = "near-sdk"))]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, BorshSerialize, BorshDeserialize,
+)]
+pub struct U128(pub u128);
+
+#[cfg(not(feature = "near-sdk"))]
+impl From<u128> for U128 {
+    fn from(value: u128) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(not(feature = "near-sdk"))]
+impl Serialize for U128 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+#[cfg(not(feature = "near-sdk"))]
+impl<'de> Deserialize<'de> for U128 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        value.parse().map(Self).map_err(serde::de::Error::custom)
+    }
+}
+
+/// One transaction's confirmation change in a batch event
+///
+/// Same fields as `TransactionConfirmed` / `ConfirmationRevoked`, with `owner` in place of
+/// `confirmer` / `revoker`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmationEntry {
+    pub tx_id: u64,
+    pub owner: AccountId,
+    /// Confirmation count after the change
+    pub confirmations: u32,
+}
+
+/// Events emitted for off-chain indexing
+///
+/// Serializes as the NEP-297 `event` / `data` pair; [`MultisigEvent::to_log`] adds the
+/// `standard` and `version` envelope. Deserializes from the full envelope too, ignoring
+/// `standard` and `version`; use [`MultisigEvent::from_log`] to check them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", content = "data", rename_all = "snake_case")]
+pub enum MultisigEvent {
+    TransactionSubmitted {
+        tx_id: u64,
+        submitter: AccountId,
+        receiver_id: AccountId,
+    },
+
+    TransactionConfirmed {
+        tx_id: u64,
+        confirmer: AccountId,
+        confirmations: u32,
+    },
+
+    TransactionExecuted {
+        tx_id: u64,
+        success: bool,
+    },
+
+    TransactionCancelled {
+        tx_id: u64,
+        canceller: AccountId,
+    },
+
+    CancelRequested {
+        tx_id: u64,
+        requester: AccountId,
+    },
+
+    CancelVoteCast {
+        tx_id: u64,
+        voter: AccountId,
+        votes: u32,
+        required: u32,
+    },
+
+    ConfirmationRevoked {
+        tx_id: u64,
+        revoker: AccountId,
+        confirmations: u32,
+    },
+
+    TransactionConfirmationInvalidated {
+        tx_id: u64,
+        account: AccountId,
+    },
+
+    CallbackGasChanged {
+        old_gas: u64,
+        new_gas: u64,
+        changer: AccountId,
+    },
+
+    RefundCallbackGasChanged {
+        old_gas: u64,
+        new_gas: u64,
+        changer: AccountId,
+    },
+
+    StorageDepositChanged {
+        old_deposit: u128,
+        new_deposit: u128,
+        changer: AccountId,
+    },
+
+    MaxReservedBpsChanged {
+        old_bps: u32,
+        new_bps: u32,
+        changer: AccountId,
+    },
+
+    MinFunctionCallGasChanged {
+        old_gas: u64,
+        new_gas: u64,
+        changer: AccountId,
+    },
+
+    TransactionRetried {
+        tx_id: u64,
+        retry_count: u32,
+        callback_gas: u64,
+        retrier: AccountId,
+    },
+
+    OwnerKeyRegistered {
+        owner: AccountId,
+        public_key: PublicKey,
+    },
+
+    SessionKeyRegistered {
+        owner: AccountId,
+        public_key: PublicKey,
+        expires_at: u64,
+    },
+
+    SessionKeyRevoked {
+        owner: AccountId,
+        public_key: PublicKey,
+    },
+
+    CleanupGracePeriodChanged {
+        old_period: u64,
+        new_period: u64,
+        changer: AccountId,
+    },
+
+    DefaultExpirationChanged {
+        old_hours: Option<u64>,
+        new_hours: Option<u64>,
+        changer: AccountId,
+    },
+
+    MaxExecutorRewardChanged {
+        old_reward: u128,
+        new_reward: u128,
+        changer: AccountId,
+    },
+
+    MaxTransactionValueChanged {
+        old_value: Option<u128>,
+        new_value: Option<u128>,
+        changer: AccountId,
+    },
+
+    ExpirationPolicyChanged {
+        old_policy: ExpirationPolicy,
+        new_policy: ExpirationPolicy,
+        changer: AccountId,
+    },
+
+    MandatorySignerChanged {
+        old_signer: Option<AccountId>,
+        new_signer: Option<AccountId>,
+        changer: AccountId,
+    },
+
+    ThresholdCancelChanged {
+        old_enabled: bool,
+        new_enabled: bool,
+        changer: AccountId,
+    },
+
+    AutoCleanupChanged {
+        old_enabled: bool,
+        new_enabled: bool,
+        changer: AccountId,
+    },
+
+    RelayerAdded {
+        relayer: AccountId,
+        changer: AccountId,
+    },
+
+    RelayerRemoved {
+        relayer: AccountId,
+        changer: AccountId,
+    },
+
+    DepositExemptionAdded {
+        account: AccountId,
+        changer: AccountId,
+    },
+
+    DepositExemptionRemoved {
+        account: AccountId,
+        changer: AccountId,
+    },
+
+    DailyDigest {
+        pending_count: u64,
+        executed_today: u64,
+        cancelled_today: u64,
+        reserved_balance: u128,
+        timestamp: u64,
+    },
+
+    PartialExecutionDetected {
+        tx_id: u64,
+        failed_action_index: u32,
+    },
+
+    ReceiverOfferSent {
+        tx_id: u64,
+        receiver_id: AccountId,
+        amount: u128,
+    },
+
+    ReceiverOfferAccepted {
+        tx_id: u64,
+        receiver_id: AccountId,
+    },
+
+    ReceiverOfferDeclined {
+        tx_id: u64,
+        receiver_id: AccountId,
+    },
+
+    TransactionReady {
+        tx_id: u64,
+        confirmations: u32,
+    },
+
+    TransactionsCleanedUp {
+        count: u64,
+        from_index: u64,
+        to_index: u64,
+        cleaner: AccountId,
+    },
+
+    PendingCallbacksReset {
+        previous_count: u32,
+    },
+
+    ReservationReleased {
+        tx_id: u64,
+        amount: u128,
+    },
+
+    ReservedBalanceRecomputed {
+        previous_balance: u128,
+        new_balance: u128,
+    },
+
+    MaxConcurrentExecutionsChanged {
+        old_max: u32,
+        new_max: u32,
+        changer: AccountId,
+    },
+
+    MethodAllowed {
+        receiver_id: AccountId,
+        method_name: String,
+        changer: AccountId,
+    },
+
+    MethodDisallowed {
+        receiver_id: AccountId,
+        method_name: String,
+        changer: AccountId,
+    },
+
+    /// Batch form: `data` is an array with one entry per transaction confirmed
+    TransactionsConfirmed(Vec<ConfirmationEntry>),
+
+    /// Batch form: `data` is an array with one entry per transaction revoked
+    ConfirmationsRevoked(Vec<ConfirmationEntry>),
+
+    ImportCompleted {
+        count: u32,
+    },
+
+    MaxDepositPerActionChanged {
+        old_max: Option<u128>,
+        new_max: Option<u128>,
+        changer: AccountId,
+    },
+
+    MaxProposalLifetimeChanged {
+        old_lifetime: u64,
+        new_lifetime: u64,
+        changer: AccountId,
+    },
+
+    KeyRotationProposed {
+        tx_id: u64,
+        old_key: PublicKey,
+        new_key: PublicKey,
+        proposer: AccountId,
+    },
+}
+
+impl MultisigEvent {
+    /// The `EVENT_JSON:` log line for this event, under `EVENT_STANDARD` and `EVENT_VERSION`
+    pub fn to_log(&self) -> String {
+        let body = serde_json::to_string(self).expect("Failed to serialize event");
+        // `body` is `{"event":...,"data":...}`; the envelope fields go in front, as NEP-297 orders them
+        format!(
+            "{}{{\"standard\":\"{}\",\"version\":\"{}\",{}",
+            EVENT_LOG_PREFIX,
+            EVENT_STANDARD,
+            EVENT_VERSION,
+            &body[1..]
+        )
+    }
+
+    /// Logs the event from the contract
+    #[cfg(feature = "near-sdk")]
+    pub fn emit(&self) {
+        near_sdk::env::log_str(&self.to_log());
+    }
+
+    /// Decodes an `EVENT_JSON:` log line from the contract, returning `None` for plain logs and
+    /// events from another standard
+    pub fn from_log(log: &str) -> Option<Self> {
+        Self::from_log_with_standard(log, EVENT_STANDARD)
+    }
+
+    /// Like [`MultisigEvent::from_log`], for contracts built with a custom event standard
+    pub fn from_log_with_standard(log: &str, standard: &str) -> Option<Self> {
+        let json = log.strip_prefix(EVENT_LOG_PREFIX)?.trim();
+        // Not routed through `serde_json::Value`, which can't hold u128 amounts
+        let envelope: Envelope = serde_json::from_str(json).ok()?;
+        if envelope.standard != standard {
+            return None;
+        }
+        serde_json::from_str(json).ok()
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    standard: String,
+}